@@ -50,14 +50,46 @@ impl syn::parse::Parse for ComponentParams {
 pub fn component(attr: TokenStream, item: TokenStream) -> TokenStream {
     let params = syn::parse_macro_input!(attr as ComponentParams);
 
-    let ast: syn::Item = syn::parse(item).unwrap();
-    match ast {
+    let ast = syn::parse_macro_input!(item as syn::Item);
+    let res = match ast {
         syn::Item::Struct(struct_ast) => component_from_struct(params, struct_ast),
         syn::Item::Impl(impl_ast) => component_from_impl(params, impl_ast),
-        _ => {
-            panic!("The #[component] macro can only be used on struct definition or an impl block")
-        }
-    }
+        _ => Err(syn::Error::new_spanned(
+            ast,
+            "The #[component] macro can only be used on struct definition or an impl block",
+        )),
+    };
+    res.unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[proc_macro_attribute]
+pub fn provider(_args: TokenStream, item: TokenStream) -> TokenStream {
+    let ast = syn::parse_macro_input!(item as syn::Item);
+    let res = match ast {
+        syn::Item::Fn(fn_ast) => component_from_provider(fn_ast),
+        _ => Err(syn::Error::new_spanned(
+            ast,
+            "The #[provider] macro can only be used on a function",
+        )),
+    };
+    res.unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[proc_macro_attribute]
+pub fn implements(_args: TokenStream, item: TokenStream) -> TokenStream {
+    let ast = syn::parse_macro_input!(item as syn::Item);
+    let res = match ast {
+        syn::Item::Impl(impl_ast) => implements_from_impl(impl_ast),
+        _ => Err(syn::Error::new_spanned(
+            ast,
+            "The #[implements] macro can only be used on a trait impl block",
+        )),
+    };
+    res.unwrap_or_else(|err| err.to_compile_error().into())
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -83,33 +115,62 @@ pub fn meta(_args: TokenStream, item: TokenStream) -> TokenStream {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn component_from_struct(params: ComponentParams, mut ast: syn::ItemStruct) -> TokenStream {
-    let impl_name = &ast.ident;
-    let impl_type = syn::parse2(quote! { #impl_name }).unwrap();
-    let impl_generics = syn::parse2(quote! {}).unwrap();
+#[proc_macro_attribute]
+pub fn instantiate(_args: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
 
-    let args: Vec<_> = ast
-        .fields
-        .iter_mut()
-        .map(|f| {
-            (
-                f.ident.clone().unwrap(),
-                f.ty.clone(),
-                extract_attr_explicit(&mut f.attrs),
-            )
-        })
-        .collect();
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn component_from_struct(
+    params: ComponentParams,
+    mut ast: syn::ItemStruct,
+) -> syn::Result<TokenStream> {
+    let impl_name = &ast.ident;
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    let impl_type = syn::parse_quote! { #impl_name #ty_generics };
+    let impl_generics = ast.generics.clone();
+
+    let mut assisted = Vec::new();
+    let mut args = Vec::new();
+    for f in &mut ast.fields {
+        let Some(name) = f.ident.clone() else {
+            return Err(syn::Error::new_spanned(
+                f,
+                "The #[component] macro can only be used on structs with named fields",
+            ));
+        };
+        if extract_attr_flag(&mut f.attrs, "assisted") {
+            assisted.push(name.clone());
+        }
+        let source = extract_arg_source(&mut f.attrs, &f.ty)?;
+        args.push((name, f.ty.clone(), source));
+    }
 
     let scope_type =
-        get_scope(&ast.attrs).unwrap_or_else(|| syn::parse_str("::dill::Transient").unwrap());
+        get_scope(&ast.attrs)?.unwrap_or_else(|| syn::parse_quote! { ::dill::Transient });
 
-    let interfaces = get_interfaces(&ast.attrs);
-    let meta = get_meta(&ast.attrs);
+    let (interfaces, upcasts) = get_interfaces(&ast.attrs)?;
+    let meta = get_meta(&ast.attrs)?;
+    let instantiations = get_instantiations(&ast.attrs)?;
 
     let mut stream: TokenStream = quote! { #ast }.into();
 
     if !params.no_new {
-        stream.extend(implement_new(&impl_type, &args));
+        stream.extend(implement_new(&impl_type, &impl_generics, &args));
+    }
+
+    if !assisted.is_empty() {
+        stream.extend(implement_assisted(
+            &ast.vis,
+            &impl_type,
+            &impl_generics,
+            &ast.attrs,
+            args,
+            assisted,
+            !params.no_new,
+        )?);
+        return Ok(stream);
     }
 
     let builder: TokenStream = implement_builder(
@@ -118,50 +179,77 @@ fn component_from_struct(params: ComponentParams, mut ast: syn::ItemStruct) -> T
         &impl_generics,
         scope_type,
         interfaces,
+        upcasts,
         meta,
+        instantiations,
         args,
-        !params.no_new,
-    );
+        Vec::new(),
+        if params.no_new {
+            Ctor::Fields
+        } else {
+            Ctor::New
+        },
+    )?;
 
     stream.extend(builder);
-    stream
+    Ok(stream)
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn component_from_impl(params: ComponentParams, mut ast: syn::ItemImpl) -> TokenStream {
+fn component_from_impl(
+    params: ComponentParams,
+    mut ast: syn::ItemImpl,
+) -> syn::Result<TokenStream> {
     let impl_generics = &ast.generics;
     let impl_type = &ast.self_ty;
-    let new = get_new(&mut ast.items).expect(
-        "When using #[component] macro on the impl block it's expected to contain a new() \
-         function. Otherwise use #[derive(Builder)] on the struct.",
-    );
+    let Some(new) = get_new(&mut ast.items) else {
+        return Err(syn::Error::new_spanned(
+            impl_type,
+            "When using #[component] macro on the impl block it's expected to contain a new() \
+             function. Otherwise use #[component] on the struct.",
+        ));
+    };
 
-    let args: Vec<_> = new
-        .sig
-        .inputs
-        .iter_mut()
-        .map(|arg| match arg {
-            syn::FnArg::Typed(targ) => targ,
-            _ => panic!("Unexpected argument in new() function"),
-        })
-        .map(|arg| {
-            (
-                match arg.pat.as_ref() {
-                    syn::Pat::Ident(ident) => ident.ident.clone(),
-                    _ => panic!("Unexpected format of arguments in new() function"),
-                },
-                arg.ty.as_ref().clone(),
-                extract_attr_explicit(&mut arg.attrs),
-            )
-        })
-        .collect();
+    let mut assisted = Vec::new();
+    let mut args = Vec::new();
+    for arg in &mut new.sig.inputs {
+        let (name, arg) = get_arg_name(arg, "new() function")?;
+        if extract_attr_flag(&mut arg.attrs, "assisted") {
+            assisted.push(name.clone());
+        }
+        let source = extract_arg_source(&mut arg.attrs, &arg.ty)?;
+        args.push((name, arg.ty.as_ref().clone(), source));
+    }
 
     let scope_type =
-        get_scope(&ast.attrs).unwrap_or_else(|| syn::parse_str("::dill::Transient").unwrap());
+        get_scope(&ast.attrs)?.unwrap_or_else(|| syn::parse_quote! { ::dill::Transient });
+
+    let (interfaces, upcasts) = get_interfaces(&ast.attrs)?;
+    let meta = get_meta(&ast.attrs)?;
+    let instantiations = get_instantiations(&ast.attrs)?;
 
-    let interfaces = get_interfaces(&ast.attrs);
-    let meta = get_meta(&ast.attrs);
+    let inject_methods = get_inject_methods(&mut ast.items)?;
+
+    if !assisted.is_empty() {
+        if let Some(method) = inject_methods.first() {
+            return Err(syn::Error::new_spanned(
+                &method.name,
+                "#[inject] methods are not supported on components with assisted arguments",
+            ));
+        }
+        let mut stream: TokenStream = quote! { #ast }.into();
+        stream.extend(implement_assisted(
+            &params.vis,
+            impl_type,
+            impl_generics,
+            &ast.attrs,
+            args,
+            assisted,
+            true,
+        )?);
+        return Ok(stream);
+    }
 
     let mut stream: TokenStream = quote! { #ast }.into();
     let builder: TokenStream = implement_builder(
@@ -170,24 +258,158 @@ fn component_from_impl(params: ComponentParams, mut ast: syn::ItemImpl) -> Token
         impl_generics,
         scope_type,
         interfaces,
+        upcasts,
         meta,
+        instantiations,
         args,
-        true,
+        inject_methods,
+        Ctor::New,
+    )?;
+
+    stream.extend(builder);
+    Ok(stream)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn component_from_provider(mut ast: syn::ItemFn) -> syn::Result<TokenStream> {
+    if !ast.sig.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &ast.sig.generics,
+            "#[provider] functions cannot be generic",
+        ));
+    }
+    if let Some(asyncness) = &ast.sig.asyncness {
+        return Err(syn::Error::new_spanned(
+            asyncness,
+            "#[provider] functions cannot be async",
+        ));
+    }
+
+    let impl_type = match &ast.sig.output {
+        syn::ReturnType::Type(_, typ) => typ.as_ref().clone(),
+        syn::ReturnType::Default => {
+            return Err(syn::Error::new_spanned(
+                &ast.sig,
+                "#[provider] function must return the provided type",
+            ));
+        }
+    };
+
+    let mut args = Vec::new();
+    for arg in &mut ast.sig.inputs {
+        let (name, arg) = get_arg_name(arg, "#[provider] function")?;
+        let source = extract_arg_source(&mut arg.attrs, &arg.ty)?;
+        args.push((name, arg.ty.as_ref().clone(), source));
+    }
+
+    let scope_type =
+        get_scope(&ast.attrs)?.unwrap_or_else(|| syn::parse_quote! { ::dill::Transient });
+
+    let (interfaces, upcasts) = get_interfaces(&ast.attrs)?;
+    let meta = get_meta(&ast.attrs)?;
+
+    // Provider is named after the function, e.g. `http_client` ->
+    // `HttpClientProvider`
+    let provider_name = format_ident!(
+        "{}Provider",
+        ast.sig
+            .ident
+            .to_string()
+            .split('_')
+            .map(|w| {
+                let mut chars = w.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_uppercase().chain(chars).collect::<String>())
+                    .unwrap_or_default()
+            })
+            .collect::<String>()
     );
 
+    let vis = &ast.vis;
+    let mut stream: TokenStream = quote! {
+        #ast
+
+        /// Registers the instances created by the provider function
+        #vis struct #provider_name;
+    }
+    .into();
+
+    let builder: TokenStream = implement_builder(
+        &ast.vis,
+        &impl_type,
+        &syn::Generics::default(),
+        scope_type,
+        interfaces,
+        upcasts,
+        meta,
+        Vec::new(),
+        args,
+        Vec::new(),
+        Ctor::Provider {
+            func: ast.sig.ident.clone(),
+            component: provider_name,
+        },
+    )?;
+
     stream.extend(builder);
-    stream
+    Ok(stream)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn implements_from_impl(ast: syn::ItemImpl) -> syn::Result<TokenStream> {
+    let Some((None, trait_path, _)) = &ast.trait_ else {
+        return Err(syn::Error::new_spanned(
+            &ast.self_ty,
+            "The #[implements] macro can only be used on a trait impl block",
+        ));
+    };
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &ast.generics,
+            "#[implements] cannot be used on generic impl blocks, use #[interface(...)] on the \
+             component instead",
+        ));
+    }
+
+    // Binding is looked up by the type, so it works with aliases and with the
+    // builders of any kind (e.g. `#[provider]`s of foreign types). Builders
+    // can already be cast into any interface the component implements.
+    let impl_type = &ast.self_ty;
+    let iface: syn::Type = syn::parse_quote! { dyn #trait_path };
+
+    Ok(quote! {
+        #ast
+
+        ::dill::registry::inventory::submit! {
+            ::dill::registry::Implementation {
+                impl_type: ::dill::TypeInfo::of::<#impl_type>,
+                iface: ::dill::TypeInfo::of::<#iface>,
+                bind: |cat| {
+                    cat.bind::<#iface, #impl_type>();
+                },
+            }
+        }
+    }
+    .into())
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[allow(clippy::too_many_arguments)]
-fn implement_new(impl_type: &syn::Type, args: &[(syn::Ident, syn::Type, bool)]) -> TokenStream {
+fn implement_new(
+    impl_type: &syn::Type,
+    impl_generics: &syn::Generics,
+    args: &[(syn::Ident, syn::Type, ArgSource)],
+) -> TokenStream {
     let arg_decl = args.iter().map(|(name, ty, _)| quote! {#name: #ty});
     let arg_name = args.iter().map(|(name, _, _)| name);
+    let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
 
     quote! {
-        impl #impl_type {
+        impl #impl_generics #impl_type #where_clause {
             #[allow(clippy::too_many_arguments)]
             pub fn new(
                 #(#arg_decl),*
@@ -203,18 +425,88 @@ fn implement_new(impl_type: &syn::Type, args: &[(syn::Ident, syn::Type, bool)])
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// How the builder constructs the instance
+enum Ctor {
+    /// Struct literal initializing all fields
+    Fields,
+    /// `Impl::new()` function
+    New,
+    /// `#[provider]` function, with the generated type that represents it
+    Provider {
+        func: syn::Ident,
+        component: syn::Ident,
+    },
+}
+
 #[allow(clippy::too_many_arguments)]
 fn implement_builder(
     impl_vis: &syn::Visibility,
     impl_type: &syn::Type,
-    _impl_generics: &syn::Generics,
+    impl_generics: &syn::Generics,
     scope_type: syn::Path,
     interfaces: Vec<syn::Type>,
+    upcasts: Vec<(syn::Type, syn::Type)>,
     meta: Vec<syn::ExprStruct>,
-    args: Vec<(syn::Ident, syn::Type, bool)>,
-    has_new: bool,
-) -> TokenStream {
-    let builder_name = format_ident!("{}Builder", quote! { #impl_type }.to_string());
+    instantiations: Vec<GenericArguments>,
+    args: Vec<(syn::Ident, syn::Type, ArgSource)>,
+    inject_methods: Vec<InjectMethod>,
+    ctor: Ctor,
+) -> syn::Result<TokenStream> {
+    // Provided types are not owned by the crate, so the builder is named after
+    // the provider and `Component` is implemented on it
+    let (builder_name, component_type) = match &ctor {
+        Ctor::Provider { component, .. } => (
+            format_ident!("{}Builder", component),
+            syn::parse_quote! { #component },
+        ),
+        _ => (
+            format_ident!("{}Builder", get_type_ident(impl_type)?),
+            impl_type.clone(),
+        ),
+    };
+
+    if let Some(instantiation) = instantiations.first() {
+        if impl_generics.params.is_empty() {
+            return Err(syn::Error::new_spanned(
+                instantiation,
+                "#[instantiate(...)] can only be used on generic components",
+            ));
+        }
+        if let Some((name, _, _)) = args.iter().find(|(_, _, source)| source.is_explicit()) {
+            return Err(syn::Error::new_spanned(
+                name,
+                "#[instantiate(...)] cannot be used on components with explicit arguments",
+            ));
+        }
+    }
+
+    // Generic components require all type parameters to be 'static and the
+    // instance to be shareable between threads
+    let mut generics = impl_generics.clone();
+    if !generics.params.is_empty() {
+        let where_clause = generics.make_where_clause();
+        where_clause
+            .predicates
+            .push(syn::parse_quote! { #impl_type: Send + Sync + 'static });
+        for param in impl_generics.type_params() {
+            let ident = &param.ident;
+            where_clause
+                .predicates
+                .push(syn::parse_quote! { #ident: 'static });
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let builder_type = quote! { #builder_name #ty_generics };
+
+    // Unused type parameters are not allowed in the builder struct
+    let (phantom_field, phantom_field_ctor) = if generics.params.is_empty() {
+        (quote! {}, quote! {})
+    } else {
+        (
+            quote! { _dill_phantom: ::std::marker::PhantomData<fn() -> #impl_type>, },
+            quote! { _dill_phantom: ::std::marker::PhantomData, },
+        )
+    };
 
     let arg_name: Vec<_> = args.iter().map(|(name, _, _)| name).collect();
 
@@ -236,7 +528,7 @@ fn implement_builder(
     let mut arg_provide_dependency = Vec::new();
     let mut arg_dependency_info = Vec::new();
 
-    for (name, typ, is_explicit) in &args {
+    for (name, typ, source) in &args {
         let (
             override_fn_field,
             override_fn_field_ctor,
@@ -244,7 +536,7 @@ fn implement_builder(
             prepare_dependency,
             provide_dependency,
             dependency_info,
-        ) = implement_arg(name, typ, &builder_name, &scope_type, *is_explicit);
+        ) = implement_arg(name, typ, &scope_type, source)?;
 
         arg_override_fn_field.push(override_fn_field);
         arg_override_fn_field_ctor.push(override_fn_field_ctor);
@@ -261,48 +553,71 @@ fn implement_builder(
     arg_provide_dependency.retain(|t| !t.is_empty());
     arg_dependency_info.retain(|t| !t.is_empty());
 
+    let mut method_calls = Vec::new();
+    for method in &inject_methods {
+        let (call, dependency_info) = implement_inject_method(method, &scope_type)?;
+        method_calls.push(call);
+        arg_dependency_info.extend(dependency_info);
+    }
+
     let explicit_arg_decl: Vec<_> = args
         .iter()
-        .filter(|(_, _, is_explicit)| *is_explicit)
+        .filter(|(_, _, source)| source.is_explicit())
         .map(|(ident, ty, _)| quote! { #ident: #ty })
         .collect();
     let explicit_arg_provide: Vec<_> = args
         .iter()
-        .filter(|(_, _, is_explicit)| *is_explicit)
+        .filter(|(_, _, source)| source.is_explicit())
         .map(|(ident, _, _)| quote! { #ident })
         .collect();
 
-    let ctor = if !has_new {
-        quote! {
-            #impl_type {
-                #( #arg_name: #arg_provide_dependency, )*
+    let ctor = match &ctor {
+        Ctor::Fields => {
+            let impl_ident = get_type_ident(impl_type)?;
+            quote! {
+                #impl_ident {
+                    #( #arg_name: #arg_provide_dependency, )*
+                }
             }
         }
+        Ctor::New => quote! {
+            <#impl_type>::new(#( #arg_provide_dependency, )*)
+        },
+        Ctor::Provider { func, .. } => quote! {
+            #func(#( #arg_provide_dependency, )*)
+        },
+    };
+
+    // Injection methods are called after construction
+    let build_inst = if method_calls.is_empty() {
+        quote! { Ok(#ctor) }
     } else {
         quote! {
-            #impl_type::new(#( #arg_provide_dependency, )*)
+            let mut dill_inst = #ctor;
+            #( #method_calls )*
+            Ok(dill_inst)
         }
     };
 
     let component_or_explicit_factory = if explicit_arg_decl.is_empty() {
         quote! {
-            impl ::dill::Component for #impl_type {
+            impl #impl_generics ::dill::Component for #component_type #where_clause {
                 type Impl = #impl_type;
-                type Builder = #builder_name;
+                type Builder = #builder_type;
 
                 fn builder() -> Self::Builder {
-                    #builder_name::new()
+                    <#builder_type>::new()
                 }
             }
         }
     } else {
         quote! {
-            impl #impl_type {
+            impl #impl_generics #component_type #where_clause {
                 #[allow(clippy::too_many_arguments)]
                 pub fn builder(
                     #(#explicit_arg_decl),*
-                ) -> #builder_name {
-                    #builder_name::new(
+                ) -> #builder_type {
+                    <#builder_type>::new(
                         #(#explicit_arg_provide),*
                     )
                 }
@@ -310,20 +625,27 @@ fn implement_builder(
         }
     };
 
+    let typed_builder_cast = implement_typed_builder_cast(&generics, &builder_type, impl_type);
+
+    let upcast_from = upcasts.iter().map(|(from, _)| from);
+    let upcast_to = upcasts.iter().map(|(_, to)| to);
+
     let builder = quote! {
-        #impl_vis struct #builder_name {
+        #impl_vis struct #builder_name #impl_generics #where_clause {
             dill_builder_scope: #scope_type,
+            #phantom_field
             #(#arg_override_fn_field),*
         }
 
-        impl #builder_name {
+        impl #impl_generics #builder_type #where_clause {
             #( #meta_vars )*
 
             pub fn new(
                 #(#explicit_arg_decl),*
             ) -> Self {
                 Self {
-                    dill_builder_scope: #scope_type::new(),
+                    dill_builder_scope: <#scope_type>::new(),
+                    #phantom_field_ctor
                     #(#arg_override_fn_field_ctor),*
                 }
             }
@@ -336,11 +658,11 @@ fn implement_builder(
 
                 use ::dill::DependencySpec;
                 #( #arg_prepare_dependency )*
-                Ok(#ctor)
+                #build_inst
             }
         }
 
-        impl ::dill::Builder for #builder_name {
+        impl #impl_generics ::dill::Builder for #builder_type #where_clause {
             fn instance_type(&self) -> ::dill::TypeInfo {
                 ::dill::TypeInfo::of::<#impl_type>()
             }
@@ -349,10 +671,18 @@ fn implement_builder(
                 ::dill::TypeInfo::of::<#scope_type>()
             }
 
+            fn scope_lifetime(&self) -> ::dill::TypeInfo {
+                <#scope_type as ::dill::Scope>::lifetime()
+            }
+
             fn interfaces(&self, clb: &mut dyn FnMut(&::dill::TypeInfo) -> bool) {
                 #(
                     if !clb(&::dill::TypeInfo::of::<#interfaces>()) { return }
                 )*
+                ::dill::registry::interfaces::<#impl_type>(
+                    &[#( ::dill::TypeInfo::of::<#interfaces>() ),*],
+                    clb,
+                );
             }
 
             fn dependencies(&self, clb: &mut dyn FnMut(&::dill::DependencyInfo) -> bool) {
@@ -368,104 +698,398 @@ fn implement_builder(
             fn get_any(&self, cat: &::dill::Catalog, ctx: &::dill::InjectionContext) -> Result<::std::sync::Arc<dyn ::std::any::Any + Send + Sync>, ::dill::InjectionError> {
                 Ok(::dill::TypedBuilder::get_with_context(self, cat, ctx)?)
             }
+
+            fn evict(&self, cat: &::dill::Catalog) {
+                ::dill::Scope::evict(&self.dill_builder_scope, cat)
+            }
         }
 
-        impl ::dill::TypedBuilder<#impl_type> for #builder_name {
+        impl #impl_generics ::dill::TypedBuilder<#impl_type> for #builder_type #where_clause {
             fn get_with_context(&self, cat: &::dill::Catalog, ctx: &::dill::InjectionContext) -> Result<std::sync::Arc<#impl_type>, ::dill::InjectionError> {
                 use ::dill::Scope;
 
-                let inst = self.dill_builder_scope.get_or_create(cat, || {
-                    let inst = self.build(cat, ctx)?;
-                    Ok(::std::sync::Arc::new(inst))
+                let inst = self.dill_builder_scope.get_or_create(cat, ctx, ::dill::TypeInfo::of::<#impl_type>(), || {
+                    let inst = ::dill::cyclic::construct(|| self.build(cat, ctx))?;
+                    Ok(inst)
                 })?;
 
                 Ok(inst.downcast().unwrap())
             }
 
             fn bind_interfaces(&self, cat: &mut ::dill::CatalogBuilder) {
+                // Ensures that declared supertraits can be upcast to
+                #(
+                    let _: fn(&#upcast_from) -> &#upcast_to = |v| v;
+                )*
                 #(
                     cat.bind::<#interfaces, #impl_type>();
                 )*
+                ::dill::registry::bind_interfaces::<#impl_type>(
+                    &[#( ::dill::TypeInfo::of::<#interfaces>() ),*],
+                    cat,
+                );
             }
         }
 
-        #(
-            // Allows casting TypedBuilder<T> into TypedBuilder<dyn I> for all declared interfaces
-            impl ::dill::TypedBuilderCast<#interfaces> for #builder_name
-            {
-                fn cast(self) -> impl ::dill::TypedBuilder<#interfaces> {
-                    struct _B(#builder_name);
+        #typed_builder_cast
+    };
 
-                    impl ::dill::Builder for _B {
-                        fn instance_type(&self) -> ::dill::TypeInfo {
-                            self.0.instance_type()
-                        }
-                        fn scope_type(&self) -> ::dill::TypeInfo {
-                            self.0.scope_type()
-                        }
-                        fn interfaces(&self, clb: &mut dyn FnMut(&::dill::TypeInfo) -> bool) {
-                            self.0.interfaces(clb)
-                        }
-                        fn dependencies(&self, clb: &mut dyn FnMut(&::dill::DependencyInfo) -> bool) {
-                            self.0.dependencies(clb)
-                        }
-                        fn metadata<'a>(&'a self, clb: &mut dyn FnMut(&'a dyn std::any::Any) -> bool) {
-                            self.0.metadata(clb)
-                        }
-                        fn get_any(&self, cat: &::dill::Catalog, ctx: &::dill::InjectionContext) -> Result<std::sync::Arc<dyn std::any::Any + Send + Sync>, ::dill::InjectionError> {
-                            self.0.get_any(cat, ctx)
-                        }
-                    }
+    let family = if instantiations.is_empty() {
+        quote! {}
+    } else {
+        let impl_ident = get_type_ident(impl_type)?;
+        let family_name = format_ident!("{}Family", impl_ident);
+        quote! {
+            #impl_vis struct #family_name;
 
-                    impl ::dill::TypedBuilder<#interfaces> for _B {
-                        fn get_with_context(&self, cat: &::dill::Catalog, ctx: &::dill::InjectionContext) -> Result<::std::sync::Arc<#interfaces>, ::dill::InjectionError> {
-                            match self.0.get_with_context(cat, ctx) {
-                                Ok(v) => Ok(v),
-                                Err(e) => Err(e),
-                            }
-                        }
+            impl ::dill::ComponentFamily for #family_name {
+                fn register(cat: &mut ::dill::CatalogBuilder) {
+                    #(
+                        cat.add::<#impl_ident<#instantiations>>();
+                    )*
+                }
+            }
+        }
+    };
 
-                        fn bind_interfaces(&self, cat: &mut ::dill::CatalogBuilder) {
-                            self.0.bind_interfaces(cat);
-                        }
+    Ok(quote! {
+        #component_or_explicit_factory
+
+        #builder
+
+        #family
+    }
+    .into())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Allows casting `TypedBuilder<T>` into `TypedBuilder<dyn I>` for the
+/// interface
+/// Allows casting the builder into any interface the component implements,
+/// including those declared via `#[implements]`
+fn implement_typed_builder_cast(
+    generics: &syn::Generics,
+    builder_type: &proc_macro2::TokenStream,
+    impl_type: &syn::Type,
+) -> proc_macro2::TokenStream {
+    let mut generics = generics.clone();
+    generics
+        .params
+        .push(syn::parse_quote! { __I: ?Sized + Send + Sync + 'static });
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote! { #impl_type: ::dill::CastInto<__I> });
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::dill::TypedBuilderCast<__I> for #builder_type #where_clause
+        {
+            fn cast(self) -> impl ::dill::TypedBuilder<__I> {
+                struct _B #impl_generics (
+                    #builder_type,
+                    ::std::marker::PhantomData<fn() -> ::std::sync::Arc<__I>>,
+                ) #where_clause;
+
+                impl #impl_generics ::dill::Builder for _B #ty_generics #where_clause {
+                    fn instance_type(&self) -> ::dill::TypeInfo {
+                        self.0.instance_type()
+                    }
+                    fn scope_type(&self) -> ::dill::TypeInfo {
+                        self.0.scope_type()
+                    }
+                    fn scope_lifetime(&self) -> ::dill::TypeInfo {
+                        self.0.scope_lifetime()
+                    }
+                    fn interfaces(&self, clb: &mut dyn FnMut(&::dill::TypeInfo) -> bool) {
+                        self.0.interfaces(clb)
+                    }
+                    fn dependencies(&self, clb: &mut dyn FnMut(&::dill::DependencyInfo) -> bool) {
+                        self.0.dependencies(clb)
+                    }
+                    fn metadata<'a>(&'a self, clb: &mut dyn FnMut(&'a dyn std::any::Any) -> bool) {
+                        self.0.metadata(clb)
                     }
+                    fn get_any(&self, cat: &::dill::Catalog, ctx: &::dill::InjectionContext) -> Result<std::sync::Arc<dyn std::any::Any + Send + Sync>, ::dill::InjectionError> {
+                        self.0.get_any(cat, ctx)
+                    }
+                    fn evict(&self, cat: &::dill::Catalog) {
+                        self.0.evict(cat)
+                    }
+                }
 
-                    _B(self)
+                impl #impl_generics ::dill::TypedBuilder<__I> for _B #ty_generics #where_clause {
+                    fn get_with_context(&self, cat: &::dill::Catalog, ctx: &::dill::InjectionContext) -> Result<::std::sync::Arc<__I>, ::dill::InjectionError> {
+                        self.0
+                            .get_with_context(cat, ctx)
+                            .map(<#impl_type as ::dill::CastInto<__I>>::cast_arc)
+                    }
+
+                    fn bind_interfaces(&self, cat: &mut ::dill::CatalogBuilder) {
+                        self.0.bind_interfaces(cat);
+                    }
                 }
+
+                _B(self, ::std::marker::PhantomData)
             }
-        )*
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Generates a factory for components that receive some of their arguments
+/// at creation time, and a builder that registers this factory in the catalog
+fn implement_assisted(
+    impl_vis: &syn::Visibility,
+    impl_type: &syn::Type,
+    impl_generics: &syn::Generics,
+    attrs: &Vec<syn::Attribute>,
+    args: Vec<(syn::Ident, syn::Type, ArgSource)>,
+    assisted: Vec<syn::Ident>,
+    has_new: bool,
+) -> syn::Result<TokenStream> {
+    let impl_ident = get_type_ident(impl_type)?;
+    let factory_name = format_ident!("{}Factory", impl_ident);
+    let builder_name = format_ident!("{}Builder", impl_ident);
+
+    if !impl_generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            impl_generics,
+            "Generic components with assisted arguments are not supported",
+        ));
+    }
+    if let Some(attr) = attrs.iter().find(|attr| is_dill_attr(attr, "scope")) {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "Components with assisted arguments are created by their factory and cannot have a \
+             scope",
+        ));
+    }
+    if let Some(attr) = attrs
+        .iter()
+        .find(|attr| is_dill_attr(attr, "interface") || is_dill_attr(attr, "instantiate"))
+    {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "Components with assisted arguments are registered via their factory and cannot have \
+             default interfaces",
+        ));
+    }
+
+    // Matches the scope reported by the builder of the factory
+    let scope_type: syn::Path = syn::parse_quote! { ::dill::Agnostic };
+    let meta = get_meta(attrs)?;
+    let meta_vars: Vec<_> = meta
+        .iter()
+        .enumerate()
+        .map(|(i, e)| implement_meta_var(i, e))
+        .collect();
+    let meta_provide: Vec<_> = meta
+        .iter()
+        .enumerate()
+        .map(|(i, e)| implement_meta_provide(i, e))
+        .collect();
+
+    let mut create_arg_decl = Vec::new();
+    let mut arg_name = Vec::new();
+    let mut arg_prepare_dependency = Vec::new();
+    let mut arg_provide_dependency = Vec::new();
+    let mut arg_dependency_info = Vec::new();
+
+    for (name, typ, source) in &args {
+        if source.is_explicit() {
+            return Err(syn::Error::new_spanned(
+                name,
+                "Explicit arguments cannot be combined with assisted arguments",
+            ));
+        }
+
+        arg_name.push(name);
+        if assisted.contains(name) {
+            create_arg_decl.push(quote! { #name: #typ });
+            arg_provide_dependency.push(quote! { #name });
+            continue;
+        }
+
+        let injection_type = get_injection_type(typ, source, &scope_type);
+        if let InjectionType::Catalog = injection_type {
+            return Err(syn::Error::new_spanned(
+                typ,
+                "`Catalog` cannot be injected by value into components with assisted arguments as \
+                 their instances are owned by the caller of the factory. Use `CatalogWeakRef` \
+                 instead.",
+            ));
+        }
+        let do_get_dependency = get_do_get_dependency(&injection_type, typ, &scope_type)?;
+        arg_prepare_dependency.push(quote! { let #name = #do_get_dependency; });
+        arg_provide_dependency.push(match &injection_type {
+            InjectionType::Reference { .. } => quote! { #name.as_ref() },
+            _ => quote! { #name },
+        });
+        arg_dependency_info.push(get_do_get_dependency_info(&injection_type, typ)?);
+    }
+    arg_dependency_info.retain(|t| !t.is_empty());
+
+    let ctor = if !has_new {
+        quote! {
+            #impl_ident {
+                #( #arg_name: #arg_provide_dependency, )*
+            }
+        }
+    } else {
+        quote! {
+            <#impl_type>::new(#( #arg_provide_dependency, )*)
+        }
     };
 
-    quote! {
-        #component_or_explicit_factory
+    Ok(quote! {
+        #[derive(Clone)]
+        #impl_vis struct #factory_name {
+            catalog: ::dill::CatalogWeakRef,
+        }
 
-        #builder
+        impl #factory_name {
+            #[allow(clippy::too_many_arguments)]
+            pub fn create(&self, #( #create_arg_decl ),*) -> Result<#impl_type, ::dill::InjectionError> {
+                let cat = &self.catalog.upgrade();
+                let builder = #builder_name::new();
+                let ctx_root = ::dill::InjectionContext::new_root();
+                let ctx_build = ctx_root.push_build(&builder);
+                let ctx = &ctx_build;
+
+                use ::dill::DependencySpec;
+                #( #arg_prepare_dependency )*
+                Ok(#ctor)
+            }
+        }
+
+        impl ::dill::Assisted for #impl_type {
+            type Factory = #factory_name;
+        }
+
+        impl ::dill::Component for #impl_type {
+            type Impl = #factory_name;
+            type Builder = #builder_name;
+
+            fn builder() -> Self::Builder {
+                #builder_name::new()
+            }
+        }
+
+        #impl_vis struct #builder_name;
+
+        impl #builder_name {
+            #( #meta_vars )*
+
+            pub fn new() -> Self {
+                Self
+            }
+        }
+
+        impl ::dill::Builder for #builder_name {
+            fn instance_type(&self) -> ::dill::TypeInfo {
+                ::dill::TypeInfo::of::<#factory_name>()
+            }
+
+            fn scope_type(&self) -> ::dill::TypeInfo {
+                ::dill::TypeInfo::of::<::dill::Agnostic>()
+            }
+
+            fn interfaces(&self, _clb: &mut dyn FnMut(&::dill::TypeInfo) -> bool) {}
+
+            fn dependencies(&self, clb: &mut dyn FnMut(&::dill::DependencyInfo) -> bool) {
+                #(
+                    if !clb(& #arg_dependency_info) { return }
+                )*
+            }
+
+            fn metadata<'a>(&'a self, clb: & mut dyn FnMut(&'a dyn std::any::Any) -> bool) {
+                #( #meta_provide )*
+            }
+
+            fn get_any(&self, cat: &::dill::Catalog, ctx: &::dill::InjectionContext) -> Result<::std::sync::Arc<dyn ::std::any::Any + Send + Sync>, ::dill::InjectionError> {
+                Ok(::dill::TypedBuilder::get_with_context(self, cat, ctx)?)
+            }
+        }
+
+        impl ::dill::TypedBuilder<#factory_name> for #builder_name {
+            fn get_with_context(&self, cat: &::dill::Catalog, _ctx: &::dill::InjectionContext) -> Result<::std::sync::Arc<#factory_name>, ::dill::InjectionError> {
+                Ok(::std::sync::Arc::new(#factory_name {
+                    catalog: cat.weak_ref(),
+                }))
+            }
+
+            fn bind_interfaces(&self, _cat: &mut ::dill::CatalogBuilder) {}
+        }
     }
-    .into()
+    .into())
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// How the value of a component argument is provided
+enum ArgSource {
+    /// Injected according to the argument type
+    Deduced,
+    /// Passed to the builder via `#[component(explicit)]` attribute
+    Explicit,
+    /// Injected as stated by `#[inject(...)]` attribute
+    Inject(Box<InjectionType>),
+}
+
+impl ArgSource {
+    fn is_explicit(&self) -> bool {
+        matches!(self, ArgSource::Explicit)
+    }
+}
+
+/// Determines how to inject a non-explicit argument
+fn get_injection_type(
+    typ: &syn::Type,
+    source: &ArgSource,
+    scope_type: &syn::Path,
+) -> InjectionType {
+    match source {
+        ArgSource::Inject(injection_type) => injection_type.as_ref().clone(),
+        _ => deduce_injection_type_in_scope(typ, scope_type),
+    }
+}
+
+/// Same as [`types::deduce_injection_type()`], but values may turn out to be
+/// the runtime key of the scope
+fn deduce_injection_type_in_scope(typ: &syn::Type, scope_type: &syn::Path) -> InjectionType {
+    match types::deduce_injection_type(typ) {
+        InjectionType::Value { typ } => InjectionType::ValueOrKey {
+            typ,
+            scope: scope_type.clone(),
+        },
+        injection_type => injection_type,
+    }
+}
+
 fn implement_arg(
     name: &syn::Ident,
     typ: &syn::Type,
-    builder: &syn::Ident,
     scope_type: &syn::Path,
-    is_explicit: bool,
-) -> (
+    source: &ArgSource,
+) -> syn::Result<(
     proc_macro2::TokenStream, // override_fn_field
     proc_macro2::TokenStream, // override_fn_field_ctor
     proc_macro2::TokenStream, // override_setters
     proc_macro2::TokenStream, // prepare_dependency
     proc_macro2::TokenStream, // provide_dependency
     proc_macro2::TokenStream, // dependency_info
-) {
+)> {
     let override_fn_name = format_ident!("arg_{}_fn", name);
+    let is_explicit = source.is_explicit();
 
     let injection_type = if is_explicit {
         InjectionType::Value { typ: typ.clone() }
     } else {
-        types::deduce_injection_type(typ)
+        get_injection_type(typ, source, scope_type)
     };
 
     // Used to declare the field that stores the override factory function or
@@ -477,7 +1101,8 @@ fn implement_arg(
             InjectionType::Reference { .. }
             | InjectionType::Catalog
             | InjectionType::CatalogRef
-            | InjectionType::CatalogWeakRef => proc_macro2::TokenStream::new(),
+            | InjectionType::CatalogWeakRef
+            | InjectionType::Skip { .. } => proc_macro2::TokenStream::new(),
             _ => quote! {
                 #override_fn_name: Option<Box<dyn Fn(&::dill::Catalog) -> Result<#typ, ::dill::InjectionError> + Send + Sync>>
             },
@@ -493,7 +1118,8 @@ fn implement_arg(
             InjectionType::Reference { .. }
             | InjectionType::Catalog
             | InjectionType::CatalogRef
-            | InjectionType::CatalogWeakRef => proc_macro2::TokenStream::new(),
+            | InjectionType::CatalogWeakRef
+            | InjectionType::Skip { .. } => proc_macro2::TokenStream::new(),
             _ => quote! { #override_fn_name: None },
         }
     };
@@ -506,20 +1132,34 @@ fn implement_arg(
             InjectionType::Reference { .. }
             | InjectionType::Catalog
             | InjectionType::CatalogRef
-            | InjectionType::CatalogWeakRef => proc_macro2::TokenStream::new(),
+            | InjectionType::CatalogWeakRef
+            | InjectionType::Skip { .. } => proc_macro2::TokenStream::new(),
             _ => {
                 let setter_val_name = format_ident!("with_{}", name);
                 let setter_fn_name = format_ident!("with_{}_fn", name);
-                quote! {
-                    pub fn #setter_val_name(mut self, val: #typ) -> #builder {
-                        self.#override_fn_name = Some(Box::new(move |_| Ok(val.clone())));
-                        self
+
+                // Owned values cannot be cloned for every build, so only the factory
+                // function override is available for them, as well as for values of
+                // custom specs that are not guaranteed to be `Clone`
+                let setter_val = match &injection_type {
+                    InjectionType::Box { .. } | InjectionType::Spec { .. } => {
+                        proc_macro2::TokenStream::new()
                     }
+                    _ => quote! {
+                        pub fn #setter_val_name(mut self, val: #typ) -> Self {
+                            self.#override_fn_name = Some(Box::new(move |_| Ok(val.clone())));
+                            self
+                        }
+                    },
+                };
+
+                quote! {
+                    #setter_val
 
                     pub fn #setter_fn_name(
                         mut self,
                         fun: impl Fn(&::dill::Catalog) -> Result<#typ, ::dill::InjectionError> + 'static + Send + Sync
-                    ) -> #builder {
+                    ) -> Self {
                         self.#override_fn_name = Some(Box::new(fun));
                         self
                     }
@@ -532,12 +1172,13 @@ fn implement_arg(
     let prepare_dependency = if is_explicit {
         proc_macro2::TokenStream::new()
     } else {
-        let do_get_dependency = get_do_get_dependency(&injection_type, scope_type);
+        let do_get_dependency = get_do_get_dependency(&injection_type, typ, scope_type)?;
         match &injection_type {
             InjectionType::Reference { .. }
             | InjectionType::Catalog
             | InjectionType::CatalogRef
-            | InjectionType::CatalogWeakRef => {
+            | InjectionType::CatalogWeakRef
+            | InjectionType::Skip { .. } => {
                 quote! { let #name = #do_get_dependency; }
             }
             _ => quote! {
@@ -565,130 +1206,292 @@ fn implement_arg(
     let dependency_info = if is_explicit {
         proc_macro2::TokenStream::new()
     } else {
-        let info = get_do_get_dependency_info(&injection_type);
+        let info = get_do_get_dependency_info(&injection_type, typ)?;
         match &injection_type {
             InjectionType::Reference { .. }
             | InjectionType::Catalog
             | InjectionType::CatalogRef
-            | InjectionType::CatalogWeakRef => info,
+            | InjectionType::CatalogWeakRef
+            | InjectionType::Skip { .. } => info,
             _ => quote! { #info.bound(self.#override_fn_name.is_some()) },
         }
     };
 
-    (
+    Ok((
         override_fn_field,
         override_fn_field_ctor,
         override_setters,
         prepare_dependency,
         provide_dependency,
         dependency_info,
-    )
+    ))
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Method marked with `#[inject]` attribute that is called after construction
+struct InjectMethod {
+    name: syn::Ident,
+    args: Vec<(syn::Ident, syn::Type)>,
+}
+
+/// Returns the code that resolves the dependencies and calls the method on the
+/// `dill_inst` instance, along with the dependency info of all its arguments
+fn implement_inject_method(
+    method: &InjectMethod,
+    scope_type: &syn::Path,
+) -> syn::Result<(proc_macro2::TokenStream, Vec<proc_macro2::TokenStream>)> {
+    let method_name = &method.name;
+    let mut prepare_dependency = Vec::new();
+    let mut provide_dependency = Vec::new();
+    let mut dependency_info = Vec::new();
+
+    for (name, typ) in &method.args {
+        let injection_type = deduce_injection_type_in_scope(typ, scope_type);
+
+        let do_get_dependency = get_do_get_dependency(&injection_type, typ, scope_type)?;
+        prepare_dependency.push(quote! { let #name = #do_get_dependency; });
+        provide_dependency.push(match &injection_type {
+            InjectionType::Reference { .. } => quote! { #name.as_ref() },
+            _ => quote! { #name },
+        });
+        dependency_info.push(get_do_get_dependency_info(&injection_type, typ)?);
+    }
+
+    let call = quote! {
+        {
+            #( #prepare_dependency )*
+            dill_inst.#method_name(#( #provide_dependency ),*);
+        }
+    };
+
+    Ok((call, dependency_info))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the code that resolves the dependency, with errors pointing at the
+/// argument type `typ`
 fn get_do_get_dependency(
     injection_type: &InjectionType,
+    typ: &syn::Type,
     scope_type: &syn::Path,
-) -> proc_macro2::TokenStream {
-    match injection_type {
+) -> syn::Result<proc_macro2::TokenStream> {
+    Ok(match injection_type {
         InjectionType::Catalog => {
             if scope_type.segments.last().unwrap().ident != "Transient" {
-                panic!(
+                return Err(syn::Error::new_spanned(
+                    typ,
                     "`Catalog` can only be injected by value into components in a `Transient` \
                      scope as they guarantee a short lifetime. Injecting catalog into other \
-                     scopes may result in cyclic references and resource leaks."
-                );
-            } else {
-                quote! { cat.clone() }
+                     scopes may result in cyclic references and resource leaks.",
+                ));
             }
+            quote! { cat.clone() }
         }
         InjectionType::CatalogRef => quote! { cat },
         InjectionType::CatalogWeakRef => quote! { cat.weak_ref() },
-        InjectionType::Arc { inner } => {
-            quote! { cat.get_with_context::<::dill::OneOf::<#inner>>(ctx)? }
-        }
-        InjectionType::Reference { inner } => {
-            quote! { cat.get_with_context::<::dill::OneOf::<#inner>>(ctx)? }
-        }
-        InjectionType::Option { element } => match element.as_ref() {
-            InjectionType::Arc { inner } => {
-                quote! { cat.get_with_context::<::dill::Maybe::<::dill::OneOf::<#inner>>>(ctx)? }
-            }
-            InjectionType::Value { typ } => {
-                quote! { cat.get_with_context::<::dill::Maybe::<::dill::OneOf::<#typ>>>(ctx)?.map(|v| v.as_ref().clone()) }
-            }
-            _ => {
-                unimplemented!("Currently only Option<Arc<Iface>> and Option<Value> are supported")
-            }
-        },
-        InjectionType::Lazy { element } => match element.as_ref() {
-            InjectionType::Arc { inner } => {
-                quote! { cat.get_with_context::<::dill::specs::Lazy::<::dill::OneOf::<#inner>>>(ctx)? }
-            }
-            _ => unimplemented!("Currently only Lazy<Arc<Iface>> is supported"),
+        InjectionType::Skip { default } => match default {
+            Some(expr) => quote! { #expr },
+            None => quote! { ::std::default::Default::default() },
         },
-        InjectionType::Vec { item } => match item.as_ref() {
-            InjectionType::Arc { inner } => {
-                quote! { cat.get_with_context::<::dill::AllOf::<#inner>>(ctx)? }
+        _ => {
+            let SpecInfo { spec, convert, .. } = get_spec(injection_type, typ)?;
+            match convert {
+                Some(convert) => quote! { cat.get_with_context::<#spec>(ctx).map(#convert)? },
+                None => quote! { cat.get_with_context::<#spec>(ctx)? },
             }
-            _ => unimplemented!("Currently only Vec<Arc<Iface>> is supported"),
-        },
-        InjectionType::Value { typ } => {
-            quote! { cat.get_with_context::<::dill::OneOf::<#typ>>(ctx).map(|v| v.as_ref().clone())? }
         }
-    }
+    })
 }
 
-fn get_do_get_dependency_info(injection_type: &InjectionType) -> proc_macro2::TokenStream {
-    match injection_type {
+fn get_do_get_dependency_info(
+    injection_type: &InjectionType,
+    typ: &syn::Type,
+) -> syn::Result<proc_macro2::TokenStream> {
+    Ok(match injection_type {
         InjectionType::Catalog | InjectionType::CatalogRef => {
             quote! { ::dill::DependencyInfo::of::<::dill::Catalog, ::dill::specs::OneOf::<::dill::Catalog>>() }
         }
         InjectionType::CatalogWeakRef => {
             quote! { ::dill::DependencyInfo::of::<::dill::CatalogWeakRef, ::dill::specs::OneOf::<::dill::CatalogWeakRef>>() }
         }
-        InjectionType::Arc { inner } => quote! {
-            ::dill::DependencyInfo::of::<#inner, ::dill::specs::OneOf::<#inner>>()
+        InjectionType::Skip { .. } => proc_macro2::TokenStream::new(),
+        InjectionType::ValueOrKey { typ, scope } => quote! {
+            if <#scope as ::dill::Scope>::key_type() == Some(::dill::TypeInfo::of::<#typ>()) {
+                ::dill::DependencyInfo::of::<#typ, ::dill::specs::KeyOf::<#typ>>()
+            } else {
+                ::dill::DependencyInfo::of::<#typ, ::dill::specs::OneOf::<#typ>>()
+            }
         },
-        InjectionType::Reference { inner } => quote! {
-            ::dill::DependencyInfo::of::<#inner, ::dill::specs::OneOf::<#inner>>()
+        _ => {
+            let SpecInfo { spec, iface, .. } = get_spec(injection_type, typ)?;
+            quote! { ::dill::DependencyInfo::of::<#iface, #spec>() }
+        }
+    })
+}
+
+/// Spec that resolves a dependency of a certain [`InjectionType`]
+struct SpecInfo {
+    spec: proc_macro2::TokenStream,
+    iface: proc_macro2::TokenStream,
+    /// Closure that converts the value returned by the spec into the injected
+    /// type, if they differ
+    convert: Option<proc_macro2::TokenStream>,
+}
+
+fn get_spec(injection_type: &InjectionType, typ: &syn::Type) -> syn::Result<SpecInfo> {
+    let clone_value = quote! { |v| v.as_ref().clone() };
+
+    Ok(match injection_type {
+        InjectionType::Arc { inner } | InjectionType::Reference { inner } => SpecInfo {
+            spec: quote! { ::dill::specs::OneOf::<#inner> },
+            iface: quote! { #inner },
+            convert: None,
         },
-        InjectionType::Option { element } => match element.as_ref() {
-            InjectionType::Arc { inner } => {
-                quote! {
-                    ::dill::DependencyInfo::of::<#inner, ::dill::specs::Maybe::<::dill::OneOf::<#inner>>>()
-                }
-            }
-            InjectionType::Value { typ } => {
-                quote! {
-                    ::dill::DependencyInfo::of::<#typ, ::dill::specs::Maybe::<::dill::OneOf::<#typ>>>()
-                }
-            }
-            _ => {
-                unimplemented!("Currently only Option<Arc<Iface>> and Option<Value> are supported")
-            }
+        InjectionType::Value { typ } => SpecInfo {
+            spec: quote! { ::dill::specs::OneOf::<#typ> },
+            iface: quote! { #typ },
+            convert: Some(clone_value),
         },
-        InjectionType::Lazy { element } => match element.as_ref() {
-            InjectionType::Arc { inner } => {
-                quote! {
-                    ::dill::DependencyInfo::of::<#inner, ::dill::specs::Lazy::<::dill::OneOf::<#inner>>>()
-                }
-            }
-            _ => unimplemented!("Currently only Lazy<Arc<Iface>> is supported"),
+        // Resolution of keys is the same, they only differ in validation
+        InjectionType::ValueOrKey { typ, .. } => SpecInfo {
+            spec: quote! { ::dill::specs::OneOf::<#typ> },
+            iface: quote! { #typ },
+            convert: Some(clone_value),
+        },
+        InjectionType::Box { inner } => match inner {
+            // Trait objects can only be owned in a box
+            syn::Type::TraitObject(_) => SpecInfo {
+                spec: quote! { ::dill::specs::OwnedBox::<#inner> },
+                iface: quote! { #inner },
+                convert: None,
+            },
+            _ => SpecInfo {
+                spec: quote! { ::dill::specs::Owned::<#inner> },
+                iface: quote! { #inner },
+                convert: Some(quote! { Box::new }),
+            },
+        },
+        InjectionType::Weak { inner } => SpecInfo {
+            spec: quote! { ::dill::specs::WeakOf::<#inner> },
+            iface: quote! { #inner },
+            convert: None,
+        },
+        InjectionType::Spec { spec } => SpecInfo {
+            iface: quote! { <#spec as ::dill::DependencySpec>::IfaceType },
+            spec: quote! { #spec },
+            convert: None,
         },
         InjectionType::Vec { item } => match item.as_ref() {
-            InjectionType::Arc { inner } => quote! {
-                ::dill::DependencyInfo::of::<#inner, ::dill::specs::AllOf::<#inner>>()
+            InjectionType::Arc { inner } => SpecInfo {
+                spec: quote! { ::dill::specs::AllOf::<#inner> },
+                iface: quote! { #inner },
+                convert: None,
             },
-            _ => unimplemented!("Currently only Vec<Arc<Iface>> is supported"),
+            InjectionType::Value { typ } => SpecInfo {
+                spec: quote! { ::dill::specs::AllOf::<#typ> },
+                iface: quote! { #typ },
+                convert: Some(quote! { |v| v.iter().map(|v| v.as_ref().clone()).collect() }),
+            },
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    typ,
+                    "Currently only Vec<Arc<Iface>> and Vec<Value> are supported",
+                ));
+            }
         },
-        InjectionType::Value { typ } => {
-            quote! {
-                ::dill::DependencyInfo::of::<#typ, ::dill::specs::OneOf::<#typ>>()
+        InjectionType::Option { element } => {
+            let SpecInfo {
+                spec,
+                iface,
+                convert,
+            } = get_spec(element, typ)?;
+            SpecInfo {
+                spec: quote! { ::dill::specs::Maybe::<#spec> },
+                iface,
+                convert: convert.map(|c| quote! { |v| v.map(#c) }),
             }
         }
-    }
+        InjectionType::Result { ok, err } => {
+            if !err.as_ref().is_some_and(types::is_injection_error) {
+                return Err(syn::Error::new_spanned(
+                    err.as_ref().unwrap_or(typ),
+                    "Only Result<T, InjectionError> is supported, injection errors cannot be \
+                     converted into other error types. To inject a registered Result value use \
+                     #[inject(value)]",
+                ));
+            }
+            let SpecInfo {
+                spec,
+                iface,
+                convert,
+            } = get_spec(ok, typ)?;
+            SpecInfo {
+                spec: quote! { ::dill::specs::Fallible::<#spec> },
+                iface,
+                convert: convert.map(|c| quote! { |v| v.map(#c) }),
+            }
+        }
+        InjectionType::Lazy { element } => {
+            let SpecInfo {
+                spec,
+                iface,
+                convert,
+            } = get_spec(element, typ)?;
+            SpecInfo {
+                spec: quote! { ::dill::specs::Lazy::<#spec> },
+                iface,
+                convert: convert.map(|c| quote! { |v| ::dill::Lazy::new(move || v.get().map(#c)) }),
+            }
+        }
+        InjectionType::Default { inner, expr } => {
+            let SpecInfo {
+                spec,
+                iface,
+                convert,
+            } = get_spec(inner, typ)?;
+            SpecInfo {
+                spec: quote! { ::dill::specs::Maybe::<#spec> },
+                iface,
+                convert: Some(match convert {
+                    Some(c) => quote! { |v| v.map(#c).unwrap_or_else(|| #expr) },
+                    None => quote! { |v| v.unwrap_or_else(|| #expr) },
+                }),
+            }
+        }
+        InjectionType::Reloadable { element } => {
+            let SpecInfo {
+                spec,
+                iface,
+                convert,
+            } = get_spec(element, typ)?;
+            if convert.is_some() {
+                return Err(syn::Error::new_spanned(
+                    typ,
+                    "Reloadable<T> can only wrap types that are injected as is, like Arc<T>, \
+                     Vec<Arc<T>>, Option<Arc<T>>, Weak<T>, Box<dyn T> or custom specs, but not \
+                     values that are cloned from the registered instances",
+                ));
+            }
+            SpecInfo {
+                spec: quote! { ::dill::specs::Reloadable::<#spec> },
+                iface,
+                convert: None,
+            }
+        }
+        InjectionType::Catalog
+        | InjectionType::CatalogRef
+        | InjectionType::CatalogWeakRef
+        | InjectionType::Skip { .. } => {
+            return Err(syn::Error::new_spanned(
+                typ,
+                "`Catalog`, `&Catalog` and `CatalogWeakRef` can only be injected directly and \
+                 cannot be wrapped into Option, Vec, Lazy, Result or other types. To resolve a \
+                 registered type named `Catalog` use #[inject(spec = ...)]",
+            ));
+        }
+    })
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -711,52 +1514,115 @@ fn implement_meta_provide(index: usize, _expr: &syn::ExprStruct) -> proc_macro2:
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Searches for `#[scope(X)]` attribute and returns `X`
-fn get_scope(attrs: &Vec<syn::Attribute>) -> Option<syn::Path> {
+fn get_scope(attrs: &Vec<syn::Attribute>) -> syn::Result<Option<syn::Path>> {
     let mut scope = None;
 
     for attr in attrs {
         if is_dill_attr(attr, "scope") {
-            attr.parse_nested_meta(|meta| {
-                scope = Some(meta.path);
-                Ok(())
-            })
-            .expect("Could not parse scope");
+            scope = Some(attr.parse_args()?);
         }
     }
 
-    scope
+    Ok(scope)
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Searches for all `#[interface(X)]` attributes and returns all types
-fn get_interfaces(attrs: &Vec<syn::Attribute>) -> Vec<syn::Type> {
+/// Parsed `#[interface(dyn X, supertraits(dyn Y, dyn Z))]` attribute
+struct InterfaceAttr {
+    iface: syn::Type,
+    supertraits: Vec<syn::Type>,
+}
+
+impl syn::parse::Parse for InterfaceAttr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let iface = input.parse()?;
+        let mut supertraits = Vec::new();
+
+        while !input.is_empty() {
+            input.parse::<syn::Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+
+            let ident = input.parse::<syn::Ident>()?;
+            if ident != "supertraits" {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("Unexpected parameter: {ident}"),
+                ));
+            }
+
+            let content;
+            syn::parenthesized!(content in input);
+            supertraits.extend(
+                content
+                    .parse_terminated(syn::Type::parse, syn::Token![,])?
+                    .into_iter(),
+            );
+        }
+
+        Ok(Self { iface, supertraits })
+    }
+}
+
+/// Searches for all `#[interface(X)]` attributes and returns all types along
+/// with the `(interface, supertrait)` pairs. Supertraits are returned as
+/// interfaces too.
+#[allow(clippy::type_complexity)]
+fn get_interfaces(
+    attrs: &Vec<syn::Attribute>,
+) -> syn::Result<(Vec<syn::Type>, Vec<(syn::Type, syn::Type)>)> {
     let mut interfaces = Vec::new();
+    let mut upcasts = Vec::new();
 
     for attr in attrs {
         if is_dill_attr(attr, "interface") {
-            let iface = attr.parse_args().unwrap();
-            interfaces.push(iface);
+            let InterfaceAttr { iface, supertraits } = attr.parse_args()?;
+            interfaces.push(iface.clone());
+            for supertrait in supertraits {
+                upcasts.push((iface.clone(), supertrait.clone()));
+                interfaces.push(supertrait);
+            }
         }
     }
 
-    interfaces
+    Ok((interfaces, upcasts))
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Searches for all `#[meta(X)]` attributes and returns all expressions
-fn get_meta(attrs: &Vec<syn::Attribute>) -> Vec<syn::ExprStruct> {
+fn get_meta(attrs: &Vec<syn::Attribute>) -> syn::Result<Vec<syn::ExprStruct>> {
     let mut meta = Vec::new();
 
     for attr in attrs {
         if is_dill_attr(attr, "meta") {
-            let expr = attr.parse_args().unwrap();
+            let expr = attr.parse_args()?;
             meta.push(expr);
         }
     }
 
-    meta
+    Ok(meta)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+type GenericArguments = syn::punctuated::Punctuated<syn::GenericArgument, syn::Token![,]>;
+
+/// Searches for all `#[instantiate(X, Y)]` attributes and returns their
+/// generic argument lists
+fn get_instantiations(attrs: &Vec<syn::Attribute>) -> syn::Result<Vec<GenericArguments>> {
+    let mut instantiations = Vec::new();
+
+    for attr in attrs {
+        if is_dill_attr(attr, "instantiate") {
+            let args = attr.parse_args_with(GenericArguments::parse_terminated)?;
+            instantiations.push(args);
+        }
+    }
+
+    Ok(instantiations)
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -776,6 +1642,42 @@ where
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Returns the identifier of the type, stripping the generic arguments
+fn get_type_ident(typ: &syn::Type) -> syn::Result<&syn::Ident> {
+    match typ {
+        syn::Type::Path(typepath) => Ok(&typepath.path.segments.last().unwrap().ident),
+        _ => Err(syn::Error::new_spanned(
+            typ,
+            "Expected component to be a type path",
+        )),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the name of a function argument, expecting it to be a simple
+/// identifier pattern. `func` describes the function for error messages.
+fn get_arg_name<'a>(
+    arg: &'a mut syn::FnArg,
+    func: &str,
+) -> syn::Result<(syn::Ident, &'a mut syn::PatType)> {
+    let syn::FnArg::Typed(arg) = arg else {
+        return Err(syn::Error::new_spanned(
+            arg,
+            format!("Unexpected argument in {func}"),
+        ));
+    };
+    let syn::Pat::Ident(ident) = arg.pat.as_ref() else {
+        return Err(syn::Error::new_spanned(
+            &arg.pat,
+            format!("Unexpected format of arguments in {func}"),
+        ));
+    };
+    Ok((ident.ident.clone(), arg))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Searches `impl` block for `new()` method
 fn get_new(impl_items: &mut [syn::ImplItem]) -> Option<&mut syn::ImplItemFn> {
     impl_items
@@ -789,10 +1691,182 @@ fn get_new(impl_items: &mut [syn::ImplItem]) -> Option<&mut syn::ImplItemFn> {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Searches `impl` block for methods marked with `#[inject]` attribute,
+/// removing the attribute
+fn get_inject_methods(impl_items: &mut [syn::ImplItem]) -> syn::Result<Vec<InjectMethod>> {
+    let mut methods = Vec::new();
+
+    for item in impl_items {
+        let syn::ImplItem::Fn(m) = item else {
+            continue;
+        };
+
+        let len = m.attrs.len();
+        m.attrs.retain(|attr| !is_dill_attr(attr, "inject"));
+        if m.attrs.len() == len {
+            continue;
+        }
+
+        let mut inputs = m.sig.inputs.iter_mut();
+        match inputs.next() {
+            Some(syn::FnArg::Receiver(r)) if r.reference.is_some() && r.mutability.is_some() => {}
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &m.sig,
+                    format!("#[inject] method {} must take &mut self", m.sig.ident),
+                ));
+            }
+        }
+
+        let mut args = Vec::new();
+        for arg in inputs {
+            let (name, arg) = get_arg_name(arg, "#[inject] method")?;
+            args.push((name, arg.ty.as_ref().clone()));
+        }
+
+        methods.push(InjectMethod {
+            name: m.sig.ident.clone(),
+            args,
+        });
+    }
+
+    Ok(methods)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Parsed `#[inject(...)]` attribute of a field or an argument
+enum InjectAttr {
+    /// `#[inject(spec = AllOf<dyn X>)]`
+    Spec(syn::Type),
+    /// `#[inject(value)]`
+    Value,
+    /// `#[inject(skip)]` or `#[inject(skip, default = expr)]`
+    Skip(Option<syn::Expr>),
+    /// `#[inject(catalog)]`
+    Catalog,
+}
+
+impl syn::parse::Parse for InjectAttr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident = input.parse::<syn::Ident>()?;
+        let attr = match ident.to_string().as_str() {
+            "spec" => {
+                input.parse::<syn::Token![=]>()?;
+                InjectAttr::Spec(input.parse()?)
+            }
+            "value" => InjectAttr::Value,
+            "catalog" => InjectAttr::Catalog,
+            "skip" => {
+                let mut default = None;
+                if input.parse::<Option<syn::Token![,]>>()?.is_some() && !input.is_empty() {
+                    let ident = input.parse::<syn::Ident>()?;
+                    if ident != "default" {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            format!("Unexpected parameter: {ident}"),
+                        ));
+                    }
+                    input.parse::<syn::Token![=]>()?;
+                    default = Some(input.parse()?);
+                }
+                InjectAttr::Skip(default)
+            }
+            s => {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("Unexpected parameter: {s}"),
+                ));
+            }
+        };
+
+        input.parse::<Option<syn::Token![,]>>()?;
+        if !input.is_empty() {
+            return Err(input.error("Unexpected parameters"));
+        }
+        Ok(attr)
+    }
+}
+
+/// Removes `#[component(explicit)]`, `#[inject(...)]`, `#[default(...)]` and
+/// `#[default_with(...)]` attributes of an argument, returning how its value is
+/// provided
+fn extract_arg_source(attrs: &mut Vec<syn::Attribute>, typ: &syn::Type) -> syn::Result<ArgSource> {
+    let is_explicit = extract_attr_explicit(attrs);
+
+    let mut inject = None;
+    let mut default = None;
+    let mut extracted = Vec::new();
+    for attr in std::mem::take(attrs) {
+        if is_dill_attr(&attr, "inject") {
+            inject = Some(attr.parse_args::<InjectAttr>()?);
+        } else if is_dill_attr(&attr, "default") {
+            default = Some(attr.parse_args::<syn::Expr>()?);
+        } else if is_dill_attr(&attr, "default_with") {
+            let fun: syn::Path = attr.parse_args()?;
+            default = Some(syn::parse_quote! { #fun() });
+        } else {
+            attrs.push(attr);
+            continue;
+        }
+        extracted.push(attr);
+    }
+
+    if is_explicit {
+        if let Some(attr) = extracted.first() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "Explicit arguments cannot have #[inject] or #[default] attributes",
+            ));
+        }
+        return Ok(ArgSource::Explicit);
+    }
+
+    let injection_type = match inject {
+        None if default.is_none() => return Ok(ArgSource::Deduced),
+        None => types::deduce_injection_type(typ),
+        Some(InjectAttr::Spec(spec)) => InjectionType::Spec { spec },
+        Some(InjectAttr::Value) => InjectionType::Value { typ: typ.clone() },
+        Some(InjectAttr::Skip(default)) => InjectionType::Skip { default },
+        Some(InjectAttr::Catalog) => match typ {
+            syn::Type::Reference(_) => InjectionType::CatalogRef,
+            _ if types::is_catalog_weak_ref(typ) => InjectionType::CatalogWeakRef,
+            _ => InjectionType::Catalog,
+        },
+    };
+
+    let Some(default) = default else {
+        return Ok(ArgSource::Inject(Box::new(injection_type)));
+    };
+
+    // Default is used when the dependency is not registered, which collections
+    // and wrappers already handle on their own
+    match injection_type {
+        InjectionType::Arc { .. }
+        | InjectionType::Value { .. }
+        | InjectionType::Box { .. }
+        | InjectionType::Weak { .. }
+        | InjectionType::Spec { .. } => Ok(ArgSource::Inject(Box::new(InjectionType::Default {
+            inner: Box::new(injection_type),
+            expr: default,
+        }))),
+        _ => Err(syn::Error::new_spanned(
+            extracted.iter().find(|attr| !is_dill_attr(attr, "inject")),
+            "#[default] can only be used on arguments injected as Arc<T>, Box<T>, Weak<T>, values \
+             or custom specs. Use #[inject(value)] to inject collections by value.",
+        )),
+    }
+}
+
 fn extract_attr_explicit(attrs: &mut Vec<syn::Attribute>) -> bool {
+    extract_attr_flag(attrs, "explicit")
+}
+
+/// Removes `#[component(flag)]` attribute, returning whether it was present
+fn extract_attr_flag(attrs: &mut Vec<syn::Attribute>, flag: &str) -> bool {
     let mut present = false;
     attrs.retain_mut(|attr| {
-        if is_attr_explicit(attr) {
+        if is_attr_flag(attr, flag) {
             present = true;
             false
         } else {
@@ -802,12 +1876,14 @@ fn extract_attr_explicit(attrs: &mut Vec<syn::Attribute>) -> bool {
     present
 }
 
-fn is_attr_explicit(attr: &syn::Attribute) -> bool {
+fn is_attr_flag(attr: &syn::Attribute, flag: &str) -> bool {
     if !is_dill_attr(attr, "component") {
         return false;
     }
-    let syn::Meta::List(meta) = &attr.meta else {
+    let Ok(flags) = attr.parse_args_with(
+        syn::punctuated::Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated,
+    ) else {
         return false;
     };
-    meta.tokens.to_string().contains("explicit")
+    flags.iter().any(|ident| ident == flag)
 }
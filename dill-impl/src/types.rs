@@ -2,16 +2,56 @@ use quote::ToTokens;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Clone)]
 pub(crate) enum InjectionType {
     Catalog,
     CatalogRef,
     CatalogWeakRef,
-    Arc { inner: syn::Type },
-    Reference { inner: syn::Type },
-    Option { element: Box<InjectionType> },
-    Vec { item: Box<InjectionType> },
-    Lazy { element: Box<InjectionType> },
-    Value { typ: syn::Type },
+    Arc {
+        inner: syn::Type,
+    },
+    Reference {
+        inner: syn::Type,
+    },
+    Option {
+        element: Box<InjectionType>,
+    },
+    Vec {
+        item: Box<InjectionType>,
+    },
+    Lazy {
+        element: Box<InjectionType>,
+    },
+    Reloadable {
+        element: Box<InjectionType>,
+    },
+    Result {
+        ok: Box<InjectionType>,
+        err: Option<syn::Type>,
+    },
+    Box {
+        inner: syn::Type,
+    },
+    Weak {
+        inner: syn::Type,
+    },
+    Value {
+        typ: syn::Type,
+    },
+    ValueOrKey {
+        typ: syn::Type,
+        scope: syn::Path,
+    },
+    Spec {
+        spec: syn::Type,
+    },
+    Skip {
+        default: Option<syn::Expr>,
+    },
+    Default {
+        inner: Box<Self>,
+        expr: syn::Expr,
+    },
 }
 
 pub(crate) fn deduce_injection_type(typ: &syn::Type) -> InjectionType {
@@ -37,6 +77,19 @@ pub(crate) fn deduce_injection_type(typ: &syn::Type) -> InjectionType {
         InjectionType::Lazy {
             element: Box::new(deduce_injection_type(&elem_typ)),
         }
+    } else if let Some(elem_typ) = get_reloadable_element_type(typ) {
+        InjectionType::Reloadable {
+            element: Box::new(deduce_injection_type(&elem_typ)),
+        }
+    } else if let Some((ok_typ, err_typ)) = get_result_types(typ) {
+        InjectionType::Result {
+            ok: Box::new(deduce_injection_type(&ok_typ)),
+            err: err_typ,
+        }
+    } else if let Some(inner) = get_box_element_type(typ) {
+        InjectionType::Box { inner }
+    } else if let Some(inner) = get_weak_element_type(typ) {
+        InjectionType::Weak { inner }
     } else {
         InjectionType::Value { typ: typ.clone() }
     }
@@ -65,6 +118,14 @@ pub(crate) fn is_catalog_ref(typ: &syn::Type) -> bool {
     }
 }
 
+pub(crate) fn is_injection_error(typ: &syn::Type) -> bool {
+    let syn::Type::Path(typepath) = typ else {
+        return false;
+    };
+
+    typepath.qself.is_none() && typepath.path.segments.last().unwrap().ident == "InjectionError"
+}
+
 pub(crate) fn is_catalog_weak_ref(typ: &syn::Type) -> bool {
     let syn::Type::Path(typepath) = typ else {
         return false;
@@ -86,7 +147,7 @@ pub(crate) fn strip_reference(typ: &syn::Type) -> Option<syn::Type> {
 
 pub(crate) fn get_arc_element_type(typ: &syn::Type) -> Option<syn::Type> {
     let syn::Type::Path(typepath) = typ else {
-        panic!("Expected a Type::Path");
+        return None;
     };
 
     if typepath.qself.is_some() || typepath.path.segments.last().unwrap().ident != "Arc" {
@@ -99,14 +160,14 @@ pub(crate) fn get_arc_element_type(typ: &syn::Type) -> Option<syn::Type> {
         return None;
     };
 
-    Some(syn::parse2(args.args.to_token_stream()).unwrap())
+    syn::parse2(args.args.to_token_stream()).ok()
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub(crate) fn get_option_element_type(typ: &syn::Type) -> Option<syn::Type> {
     let syn::Type::Path(typepath) = typ else {
-        panic!("Expected a Type::Path");
+        return None;
     };
 
     if typepath.qself.is_some() || &typepath.path.segments.last().unwrap().ident != "Option" {
@@ -119,14 +180,14 @@ pub(crate) fn get_option_element_type(typ: &syn::Type) -> Option<syn::Type> {
         return None;
     };
 
-    Some(syn::parse2(args.args.to_token_stream()).unwrap())
+    syn::parse2(args.args.to_token_stream()).ok()
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub(crate) fn get_lazy_element_type(typ: &syn::Type) -> Option<syn::Type> {
     let syn::Type::Path(typepath) = typ else {
-        panic!("Expected a Type::Path");
+        return None;
     };
 
     if typepath.qself.is_some() || &typepath.path.segments.last().unwrap().ident != "Lazy" {
@@ -139,14 +200,109 @@ pub(crate) fn get_lazy_element_type(typ: &syn::Type) -> Option<syn::Type> {
         return None;
     };
 
-    Some(syn::parse2(args.args.to_token_stream()).unwrap())
+    syn::parse2(args.args.to_token_stream()).ok()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) fn get_reloadable_element_type(typ: &syn::Type) -> Option<syn::Type> {
+    let syn::Type::Path(typepath) = typ else {
+        return None;
+    };
+
+    if typepath.qself.is_some() || &typepath.path.segments.last().unwrap().ident != "Reloadable" {
+        return None;
+    }
+
+    let syn::PathArguments::AngleBracketed(args) =
+        &typepath.path.segments.last().unwrap().arguments
+    else {
+        return None;
+    };
+
+    syn::parse2(args.args.to_token_stream()).ok()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Returns `T` and `E` of `Result<T, E>`, where `E` can be omitted by an alias
+pub(crate) fn get_result_types(typ: &syn::Type) -> Option<(syn::Type, Option<syn::Type>)> {
+    let syn::Type::Path(typepath) = typ else {
+        return None;
+    };
+
+    if typepath.qself.is_some() || typepath.path.segments.last().unwrap().ident != "Result" {
+        return None;
+    }
+
+    let syn::PathArguments::AngleBracketed(args) =
+        &typepath.path.segments.last().unwrap().arguments
+    else {
+        return None;
+    };
+
+    let mut types = args.args.iter().map(|arg| match arg {
+        syn::GenericArgument::Type(typ) => Some(typ.clone()),
+        _ => None,
+    });
+
+    let ok_typ = types.next()??;
+    let err_typ = types.next().flatten();
+    Some((ok_typ, err_typ))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) fn get_box_element_type(typ: &syn::Type) -> Option<syn::Type> {
+    let syn::Type::Path(typepath) = typ else {
+        return None;
+    };
+
+    if typepath.qself.is_some() || typepath.path.segments.last().unwrap().ident != "Box" {
+        return None;
+    }
+
+    let syn::PathArguments::AngleBracketed(args) =
+        &typepath.path.segments.last().unwrap().arguments
+    else {
+        return None;
+    };
+
+    syn::parse2(args.args.to_token_stream()).ok()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) fn get_weak_element_type(typ: &syn::Type) -> Option<syn::Type> {
+    let syn::Type::Path(typepath) = typ else {
+        return None;
+    };
+
+    if typepath.qself.is_some() || typepath.path.segments.last().unwrap().ident != "Weak" {
+        return None;
+    }
+
+    // Only `sync::Weak` can be injected, other spelled out paths like
+    // `std::rc::Weak` are not treated as weak references
+    let segments = &typepath.path.segments;
+    if segments.len() > 1 && segments[segments.len() - 2].ident != "sync" {
+        return None;
+    }
+
+    let syn::PathArguments::AngleBracketed(args) =
+        &typepath.path.segments.last().unwrap().arguments
+    else {
+        return None;
+    };
+
+    syn::parse2(args.args.to_token_stream()).ok()
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub(crate) fn get_vec_item_type(typ: &syn::Type) -> Option<syn::Type> {
     let syn::Type::Path(typepath) = typ else {
-        panic!("Expected a Type::Path");
+        return None;
     };
 
     if typepath.qself.is_some() || typepath.path.segments.last().unwrap().ident != "Vec" {
@@ -159,7 +315,7 @@ pub(crate) fn get_vec_item_type(typ: &syn::Type) -> Option<syn::Type> {
         return None;
     };
 
-    Some(syn::parse2(args.args.to_token_stream()).unwrap())
+    syn::parse2(args.args.to_token_stream()).ok()
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,150 @@
+use std::hint::black_box;
+use std::sync::Arc;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use dill::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+trait Plugin: Send + Sync {
+    fn id(&self) -> usize;
+}
+
+struct PluginImpl<const N: usize>;
+
+impl<const N: usize> Plugin for PluginImpl<N> {
+    fn id(&self) -> usize {
+        N
+    }
+}
+
+#[component]
+#[scope(Singleton)]
+struct Config {
+    #[allow(dead_code)]
+    name: String,
+}
+
+#[component]
+struct Service {
+    config: Arc<Config>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn add_plugin<const N: usize>(b: &mut CatalogBuilder) {
+    b.add_value(PluginImpl::<N>);
+    b.bind::<dyn Plugin, PluginImpl<N>>();
+}
+
+fn add_plugins(b: &mut CatalogBuilder) {
+    add_plugin::<0>(b);
+    add_plugin::<1>(b);
+    add_plugin::<2>(b);
+    add_plugin::<3>(b);
+    add_plugin::<4>(b);
+    add_plugin::<5>(b);
+    add_plugin::<6>(b);
+    add_plugin::<7>(b);
+    add_plugin::<8>(b);
+    add_plugin::<9>(b);
+    add_plugin::<10>(b);
+    add_plugin::<11>(b);
+    add_plugin::<12>(b);
+    add_plugin::<13>(b);
+    add_plugin::<14>(b);
+    add_plugin::<15>(b);
+}
+
+fn base_catalog() -> Catalog {
+    Catalog::builder()
+        .add::<Config>()
+        .add::<Service>()
+        .add_value("config".to_string())
+        .build()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn bench_singleton_hit(c: &mut Criterion) {
+    let cat = base_catalog();
+    cat.get_one::<Config>().unwrap();
+
+    c.bench_function("singleton_hit", |b| {
+        b.iter(|| black_box(cat.get_one::<Config>().unwrap()));
+    });
+}
+
+fn bench_transient_with_singleton_dep(c: &mut Criterion) {
+    let cat = base_catalog();
+
+    c.bench_function("transient_with_singleton_dep", |b| {
+        b.iter(|| black_box(cat.get_one::<Service>().unwrap().config.clone()));
+    });
+}
+
+fn bench_deep_chain(c: &mut Criterion) {
+    let mut cat = base_catalog();
+    for i in 0..32u64 {
+        cat = cat.builder_chained().add_value(i).build();
+    }
+    cat.get_one::<Config>().unwrap();
+
+    c.bench_function("deep_chain_one_of", |b| {
+        b.iter(|| black_box(cat.get_one::<Config>().unwrap()));
+    });
+}
+
+fn bench_wide_all_of(c: &mut Criterion) {
+    let mut b = Catalog::builder();
+    add_plugins(&mut b);
+    let cat = b.build();
+
+    c.bench_function("wide_all_of", |b| {
+        b.iter(|| {
+            let plugins = cat.get::<AllOf<dyn Plugin>>().unwrap();
+            black_box(plugins.iter().map(|p| p.id()).sum::<usize>())
+        });
+    });
+}
+
+fn bench_chained_catalog_creation(c: &mut Criterion) {
+    let cat = base_catalog();
+
+    c.bench_function("chained_catalog_creation", |b| {
+        b.iter(|| {
+            let tx = cat
+                .builder_chained()
+                .add_value(scopes::TransactionCache::new())
+                .build();
+            black_box(tx.get_one::<Service>().unwrap())
+        });
+    });
+}
+
+fn bench_overlay_catalog_creation(c: &mut Criterion) {
+    let cat = base_catalog();
+
+    c.bench_function("overlay_catalog_creation", |b| {
+        b.iter(|| {
+            let tx = cat
+                .builder_overlay()
+                .add_value(scopes::TransactionCache::new())
+                .build();
+            black_box(tx.get_one::<Service>().unwrap())
+        });
+    });
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+criterion_group!(
+    benches,
+    bench_singleton_hit,
+    bench_transient_with_singleton_dep,
+    bench_deep_chain,
+    bench_wide_all_of,
+    bench_chained_catalog_creation,
+    bench_overlay_catalog_creation,
+);
+criterion_main!(benches);
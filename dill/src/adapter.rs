@@ -0,0 +1,87 @@
+use std::any::Any;
+use std::marker::{PhantomData, Unsize};
+use std::sync::Arc;
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Builder that resolves the single implementation of the `Source` interface
+/// and wraps it into an `Adapter` which is then exposed as `Iface`.
+///
+/// See [`CatalogBuilder::adapt()`].
+pub(crate) struct AdapterBuilder<Iface, Source, Adapter>
+where
+    Iface: 'static + ?Sized,
+    Source: 'static + ?Sized,
+{
+    _dummy: PhantomData<fn(&Source, &Iface) -> Adapter>,
+}
+
+impl<Iface, Source, Adapter> AdapterBuilder<Iface, Source, Adapter>
+where
+    Iface: 'static + ?Sized,
+    Source: 'static + ?Sized,
+{
+    pub(crate) fn new() -> Self {
+        Self {
+            _dummy: PhantomData,
+        }
+    }
+}
+
+impl<Iface, Source, Adapter> Builder for AdapterBuilder<Iface, Source, Adapter>
+where
+    Iface: 'static + ?Sized + Send + Sync,
+    Source: 'static + ?Sized + Send + Sync,
+    Adapter: 'static + Send + Sync + From<Arc<Source>> + Unsize<Iface>,
+{
+    fn instance_type(&self) -> TypeInfo {
+        TypeInfo::of::<Adapter>()
+    }
+
+    fn scope_type(&self) -> TypeInfo {
+        // The adapter only holds on to the `Source` instance, so it is as
+        // long-lived as the source itself
+        TypeInfo::of::<Agnostic>()
+    }
+
+    fn interfaces(&self, clb: &mut dyn FnMut(&TypeInfo) -> bool) {
+        clb(&TypeInfo::of::<Iface>());
+    }
+
+    fn dependencies(&self, clb: &mut dyn FnMut(&DependencyInfo) -> bool) {
+        clb(&DependencyInfo::of::<Source, OneOf<Source>>());
+    }
+
+    fn metadata<'a>(&'a self, _clb: &mut dyn FnMut(&'a dyn Any) -> bool) {}
+
+    fn get_any(
+        &self,
+        cat: &Catalog,
+        ctx: &InjectionContext,
+    ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError> {
+        Ok(TypedBuilder::get_with_context(self, cat, ctx)?)
+    }
+}
+
+impl<Iface, Source, Adapter> TypedBuilder<Adapter> for AdapterBuilder<Iface, Source, Adapter>
+where
+    Iface: 'static + ?Sized + Send + Sync,
+    Source: 'static + ?Sized + Send + Sync,
+    Adapter: 'static + Send + Sync + From<Arc<Source>> + Unsize<Iface>,
+{
+    fn get_with_context(
+        &self,
+        cat: &Catalog,
+        ctx: &InjectionContext,
+    ) -> Result<Arc<Adapter>, InjectionError> {
+        let ctx_build = ctx.push_build(self);
+        let inner = cat.get_with_context::<OneOf<Source>>(&ctx_build)?;
+        Ok(Arc::new(Adapter::from(inner)))
+    }
+
+    fn bind_interfaces(&self, cat: &mut CatalogBuilder) {
+        cat.bind::<Iface, Adapter>();
+    }
+}
@@ -1,4 +1,5 @@
 use std::any::{Any, TypeId};
+use std::marker::Unsize;
 use std::sync::{Arc, Mutex};
 
 use crate::injection_context::InjectionContext;
@@ -16,6 +17,13 @@ pub trait Builder: Send + Sync {
     /// [`TypeInfo`] of the scope that caches the instances
     fn scope_type(&self) -> TypeInfo;
 
+    /// [`TypeInfo`] of the built-in scope that the lifetime of instances is
+    /// equivalent to, used by validation to detect scope inversions. See
+    /// [`Scope::lifetime()`].
+    fn scope_lifetime(&self) -> TypeInfo {
+        self.scope_type()
+    }
+
     /// Lists interfaces that the supplied type supports. Avoid using this
     /// low-level method directly - use [`BuilderExt`] convenience methods
     /// instead.
@@ -37,6 +45,11 @@ pub trait Builder: Send + Sync {
         cat: &Catalog,
         ctx: &InjectionContext,
     ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError>;
+
+    /// Asks the [Scope] to drop the cached instance(s), so that the next
+    /// resolution will create a new one. Has no effect on builders that don't
+    /// cache instances.
+    fn evict(&self, _cat: &Catalog) {}
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -188,6 +201,24 @@ pub trait TypedBuilderCast<I: Send + Sync + ?Sized> {
     fn cast(self) -> impl TypedBuilder<I>;
 }
 
+/// Implemented for all types that can be cast into `I`, e.g. into the traits
+/// they implement. Allows the code generated by the `#[component]` macro to
+/// implement [`TypedBuilderCast`] without enabling the `unsize` feature in the
+/// user crates.
+pub trait CastInto<I: ?Sized> {
+    fn cast_arc(this: Arc<Self>) -> Arc<I>;
+}
+
+impl<T, I> CastInto<I> for T
+where
+    T: Unsize<I>,
+    I: ?Sized,
+{
+    fn cast_arc(this: Arc<Self>) -> Arc<I> {
+        this
+    }
+}
+
 /// Allows [CatalogBuilder::add()] to accept types with associated builder
 pub trait Component {
     type Impl: Send + Sync;
@@ -196,6 +227,29 @@ pub trait Component {
     fn builder() -> Self::Builder;
 }
 
+/// Implemented by the `#[component]` macro for components that have
+/// `#[component(assisted)]` arguments, which are supplied when creating an
+/// instance via the generated factory rather than injected.
+///
+/// Registering such component via [CatalogBuilder::add()] registers its
+/// factory, which can then be injected as [`Factory<T>`].
+pub trait Assisted {
+    type Factory: Send + Sync + 'static;
+}
+
+/// Factory of the component with assisted arguments, e.g.
+/// `Arc<Factory<ExportJob>>`. See [`Assisted`].
+pub type Factory<T> = <T as Assisted>::Factory;
+
+/// Allows [CatalogBuilder::add_family()] to register all declared
+/// instantiations of a generic component at once.
+///
+/// Implemented by the `#[component]` macro for generic components that specify
+/// type arguments via `#[instantiate(...)]` attributes.
+pub trait ComponentFamily {
+    fn register(cat: &mut CatalogBuilder);
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct TypeInfo {
     pub id: TypeId,
@@ -215,6 +269,7 @@ impl TypeInfo {
 pub struct DependencyInfo {
     pub iface: TypeInfo,
     pub spec: TypeInfo,
+    pub kind: SpecKind,
     pub is_bound: bool,
 }
 
@@ -223,6 +278,7 @@ impl DependencyInfo {
         Self {
             iface: TypeInfo::of::<T>(),
             spec: TypeInfo::of::<Spec>(),
+            kind: Spec::KIND,
             is_bound: false,
         }
     }
@@ -259,6 +315,10 @@ where
         self.0.scope_type()
     }
 
+    fn scope_lifetime(&self) -> TypeInfo {
+        self.0.scope_lifetime()
+    }
+
     fn interfaces(&self, clb: &mut dyn FnMut(&TypeInfo) -> bool) {
         self.0.interfaces(clb);
     }
@@ -278,6 +338,10 @@ where
     ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError> {
         self.0.get_any(cat, ctx)
     }
+
+    fn evict(&self, cat: &Catalog) {
+        self.0.evict(cat);
+    }
 }
 
 impl<Bld, Impl> TypedBuilder<Impl> for TypedBuilderWithoutDefaultInterfaces<Bld>
@@ -1,34 +1,217 @@
 use std::any::Any;
-use std::sync::Arc;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::ThreadId;
+
+use crate::{InjectionContext, InjectionError, TypeInfo};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Storage for instances of caching scopes like [`crate::scopes::Cached`].
+/// Entries are identified by the scope that created them.
 pub trait Cache: Send + Sync + 'static {
     fn get(&self, id: usize) -> Option<Arc<dyn Any + Send + Sync>>;
-    fn set(&self, id: usize, inst: Arc<dyn Any + Send + Sync>);
+    fn set(&self, id: usize, instance_type: TypeInfo, inst: Arc<dyn Any + Send + Sync>);
+
+    /// Atomically returns the existing entry or creates and stores a new one.
+    ///
+    /// Implementations must guarantee that only one instance is created per
+    /// entry: concurrent callers should wait for the in-flight construction to
+    /// finish, while a re-entrant call for the same entry from within the
+    /// construction should return [`InjectionError::Cycle`].
+    fn get_or_insert_with<F>(
+        &self,
+        id: usize,
+        instance_type: TypeInfo,
+        ctx: &InjectionContext,
+        create_instance: F,
+    ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError>
+    where
+        F: FnOnce() -> Result<Arc<dyn Any + Send + Sync>, InjectionError>;
+
+    /// Removes an entry returning the instance if it was present
+    fn remove(&self, id: usize) -> Option<Arc<dyn Any + Send + Sync>>;
+
+    /// Removes all entries
+    fn clear(&self);
+
+    /// Number of cached instances
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a snapshot of cached entries
+    fn entries(&self) -> Vec<CacheEntry>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone)]
+pub struct CacheEntry {
+    pub id: usize,
+    pub instance_type: TypeInfo,
+    pub instance: Arc<dyn Any + Send + Sync>,
+}
+
+impl std::fmt::Debug for CacheEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CacheEntry")
+            .field("id", &self.id)
+            .field("instance_type", &self.instance_type)
+            .finish_non_exhaustive()
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-#[derive(Default)]
+/// Number of independently locked shards of [`CacheImpl`]
+const CACHE_SHARDS: usize = 16;
+
+/// Default [`Cache`] implementation. Entries are spread across several
+/// independently locked shards to reduce contention when many threads share
+/// the same cache.
 pub struct CacheImpl {
-    slots: Arc<std::sync::RwLock<std::collections::BTreeMap<usize, Arc<dyn Any + Send + Sync>>>>,
+    shards: Box<[std::sync::RwLock<std::collections::BTreeMap<usize, CacheEntry>>; CACHE_SHARDS]>,
+    /// Entries that are currently being constructed and the threads
+    /// constructing them
+    pending: Mutex<Vec<(usize, ThreadId)>>,
+    constructed: Condvar,
+}
+
+impl Default for CacheImpl {
+    fn default() -> Self {
+        Self {
+            shards: Box::new(std::array::from_fn(|_| Default::default())),
+            pending: Mutex::new(Vec::new()),
+            constructed: Condvar::new(),
+        }
+    }
 }
 
 impl CacheImpl {
     pub fn new() -> Self {
         Self::default()
     }
+
+    fn shard(
+        &self,
+        id: usize,
+    ) -> &std::sync::RwLock<std::collections::BTreeMap<usize, CacheEntry>> {
+        // IDs are usually addresses, so we mix the bits before picking a shard
+        let hash = (id as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        &self.shards[(hash >> (u64::BITS - CACHE_SHARDS.ilog2())) as usize]
+    }
 }
 
 impl Cache for CacheImpl {
     fn get(&self, id: usize) -> Option<Arc<dyn Any + Send + Sync>> {
-        self.slots.read().unwrap().get(&id).cloned()
+        self.shard(id)
+            .read()
+            .unwrap()
+            .get(&id)
+            .map(|e| e.instance.clone())
+    }
+
+    fn set(&self, id: usize, instance_type: TypeInfo, inst: Arc<dyn Any + Send + Sync>) {
+        self.shard(id).write().unwrap().insert(
+            id,
+            CacheEntry {
+                id,
+                instance_type,
+                instance: inst,
+            },
+        );
+    }
+
+    fn get_or_insert_with<F>(
+        &self,
+        id: usize,
+        instance_type: TypeInfo,
+        ctx: &InjectionContext,
+        create_instance: F,
+    ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError>
+    where
+        F: FnOnce() -> Result<Arc<dyn Any + Send + Sync>, InjectionError>,
+    {
+        // Fast path
+        if let Some(inst) = self.get(id) {
+            return Ok(inst);
+        }
+
+        let this_thread = std::thread::current().id();
+
+        {
+            let mut pending = self.pending.lock().unwrap();
+            loop {
+                if let Some(inst) = self.get(id) {
+                    return Ok(inst);
+                }
+                match pending.iter().find(|(pid, _)| *pid == id) {
+                    Some((_, thread)) if *thread == this_thread => {
+                        return Err(InjectionError::cycle(instance_type, ctx));
+                    }
+                    Some(_) => {
+                        pending = self.constructed.wait(pending).unwrap();
+                    }
+                    None => {
+                        pending.push((id, this_thread));
+                        break;
+                    }
+                }
+            }
+        }
+
+        // Releases the waiting threads whether construction succeeds, fails or
+        // panics
+        let _guard = PendingGuard { cache: self, id };
+        let inst = create_instance()?;
+        self.set(id, instance_type, inst.clone());
+        Ok(inst)
+    }
+
+    fn remove(&self, id: usize) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.shard(id)
+            .write()
+            .unwrap()
+            .remove(&id)
+            .map(|e| e.instance)
     }
 
-    fn set(&self, id: usize, inst: Arc<dyn Any + Send + Sync>) {
-        self.slots.write().unwrap().insert(id, inst);
+    fn clear(&self) {
+        for shard in self.shards.iter() {
+            // Instances are dropped outside the lock
+            let _slots = std::mem::take(&mut *shard.write().unwrap());
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.shards.iter().map(|s| s.read().unwrap().len()).sum()
+    }
+
+    fn entries(&self) -> Vec<CacheEntry> {
+        let mut entries: Vec<_> = self
+            .shards
+            .iter()
+            .flat_map(|s| s.read().unwrap().values().cloned().collect::<Vec<_>>())
+            .collect();
+        entries.sort_by_key(|e| e.id);
+        entries
     }
 }
 
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+struct PendingGuard<'a> {
+    cache: &'a CacheImpl,
+    id: usize,
+}
+
+impl Drop for PendingGuard<'_> {
+    fn drop(&mut self) {
+        self.cache
+            .pending
+            .lock()
+            .unwrap()
+            .retain(|(id, _)| *id != self.id);
+        self.cache.constructed.notify_all();
+    }
+}
@@ -33,6 +33,14 @@ impl Catalog {
         CatalogBuilder::new_chained(self)
     }
 
+    /// Returns a [`CatalogOverlayBuilder`] for a lightweight catalog that adds
+    /// a few values on top of this [`Catalog`]. Prefer it over
+    /// [`Catalog::builder_chained()`] for short-lived catalogs created on hot
+    /// paths, e.g. per HTTP request.
+    pub fn builder_overlay(&self) -> CatalogOverlayBuilder {
+        CatalogOverlayBuilder::new(self)
+    }
+
     /// Returns a weak reference to the catalog chain. Weak reference is useful
     /// when you want to keep using `Catalog` as a factory for complex
     /// instantiation logic, but don't want to own the strong reference that
@@ -104,6 +112,122 @@ impl Catalog {
         self.get::<OneOf<Iface>>()
     }
 
+    /// Calls the function resolving all of its arguments from the catalog
+    /// based on their types (see [`FactoryArg`]).
+    ///
+    /// Example:
+    /// ```
+    /// use std::sync::Arc;
+    /// use dill::*;
+    ///
+    /// let cat = Catalog::builder().add_value(String::from("foo")).build();
+    ///
+    /// let len = cat.call(|name: Arc<String>, count: Option<Arc<usize>>| {
+    ///     name.len() * count.map_or(1, |c| *c)
+    /// });
+    /// assert_eq!(len.unwrap(), 3);
+    /// ```
+    pub fn call<Fct, Args, R>(&self, f: Fct) -> Result<R, InjectionError>
+    where
+        Fct: FactoryFn<Args, R>,
+    {
+        f.call(self, &InjectionContext::new_root())
+    }
+
+    /// Same as [`Catalog::call()`], but awaits the future returned by the
+    /// function, e.g. `cat.call_async(|repo: Arc<dyn Repo>| async move { ..
+    /// })`. Arguments are resolved before the function is called.
+    pub async fn call_async<Fct, Args, Fut>(&self, f: Fct) -> Result<Fut::Output, InjectionError>
+    where
+        Fct: FactoryFn<Args, Fut>,
+        Fut: std::future::Future,
+    {
+        Ok(self.call(f)?.await)
+    }
+
+    /// Evicts instances of all builders bound to the interface from their
+    /// caching [`Scope`]s, so that they will be re-created upon next
+    /// resolution. Catalog determines the context of eviction, e.g. evicting
+    /// a [`Transaction`]-scoped type only affects the [`TransactionCache`]
+    /// visible from this catalog.
+    ///
+    /// [`Singleton`] instances cannot be evicted and are kept for as long as
+    /// the builder lives. To replace them, register the builder via
+    /// [`CatalogBuilder::add_reloadable()`] and use [`Catalog::reload()`].
+    ///
+    /// Previously resolved instances will be dropped once no one holds them.
+    pub fn evict<Iface>(&self)
+    where
+        Iface: 'static + ?Sized,
+    {
+        for b in self.builders_for::<Iface>() {
+            b.evict(self);
+        }
+    }
+
+    /// Replaces the builder of `Impl` registered via
+    /// [`CatalogBuilder::add_reloadable()`] in this or any of the chained
+    /// catalogs. Subsequent resolutions, including via the [`Reloadable`]
+    /// handles, will use the new builder, while the instances created by the
+    /// previous builder are dropped once no one holds them.
+    ///
+    /// Returns [`InjectionError::Unregistered`] if `Impl` was not registered as
+    /// reloadable.
+    pub fn reload<Impl>(
+        &self,
+        builder: impl TypedBuilder<Impl> + 'static,
+    ) -> Result<(), InjectionError>
+    where
+        Impl: 'static + Send + Sync,
+    {
+        let key = ImplTypeId(std::any::TypeId::of::<Impl>());
+        let slot = std::iter::once(&self.0)
+            .chain(self.0.chained_catalogs_deep(|_| true))
+            .find_map(|c| c.reloadable.get(&key))
+            .and_then(|slot| slot.downcast_ref::<ReloadableBuilder<Impl>>());
+
+        let Some(slot) = slot else {
+            return Err(InjectionError::unregistered::<Impl>(
+                &InjectionContext::new_root(),
+            ));
+        };
+
+        slot.reload(builder);
+        Ok(())
+    }
+
+    /// Resolves an instance by a specific dependency [`DependencySpec`] for the
+    /// provided runtime key. Components in a [`Keyed`] scope will cache one
+    /// instance per distinct key, and can receive the key itself via the
+    /// [`KeyOf`] spec.
+    ///
+    /// The key is made available by adding it into a short-lived overlay
+    /// catalog (see [`Self::builder_overlay()`]), so it must not be registered
+    /// in this catalog already.
+    pub fn get_keyed<Spec>(
+        &self,
+        key: impl Send + Sync + 'static,
+    ) -> Result<Spec::ReturnType, InjectionError>
+    where
+        Spec: DependencySpec + 'static,
+    {
+        self.builder_overlay().add_value(key).build().get::<Spec>()
+    }
+
+    /// Evicts the instance of `Impl` cached in a [`Keyed`] scope for the
+    /// provided key. Next call to [`Self::get_keyed()`] with the same key will
+    /// create a new instance, while existing instances are dropped once no one
+    /// holds them.
+    pub fn evict_keyed<Impl>(&self, key: impl Send + Sync + 'static)
+    where
+        Impl: 'static + ?Sized,
+    {
+        self.builder_overlay()
+            .add_value(key)
+            .build()
+            .evict::<Impl>();
+    }
+
     /// Sets this catalog as "current" in the async task scope for the duration
     /// of the provided coroutine.
     ///
@@ -15,7 +15,38 @@ use crate::*;
 pub struct CatalogBuilder {
     builders: HashMap<ImplTypeId, Arc<dyn Builder>>,
     bindings: MultiMap<IfaceTypeId, Binding>,
-    chained_catalog: Option<Arc<CatalogImpl>>,
+    reloadable: ReloadableBuilders,
+    chained_catalogs: ChainedCatalogs,
+    shadowing: Shadowing,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Controls how bindings of a chained catalog interact with the bindings of
+/// the same interface in the catalog it is chained to.
+///
+/// See [`CatalogBuilder::shadowing()`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Shadowing {
+    /// Bindings are merged, so [`OneOf`] becomes ambiguous if both catalogs
+    /// bind the same interface
+    #[default]
+    Disabled,
+    /// The nearest catalog wins for [`OneOf`], while [`AllOf`] still returns
+    /// implementations from both catalogs
+    OneOf,
+    /// The nearest catalog wins for both [`OneOf`] and [`AllOf`]
+    All,
+}
+
+impl Shadowing {
+    pub(crate) fn hides_parent(self, view: BindingsView) -> bool {
+        match self {
+            Shadowing::Disabled => false,
+            Shadowing::OneOf => view == BindingsView::OneOf,
+            Shadowing::All => true,
+        }
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -31,7 +62,9 @@ impl CatalogBuilder {
         Self {
             builders: HashMap::new(),
             bindings: MultiMap::new(),
-            chained_catalog: None,
+            reloadable: HashMap::new(),
+            chained_catalogs: ChainedCatalogs::new(),
+            shadowing: Shadowing::Disabled,
         }
     }
 
@@ -39,8 +72,90 @@ impl CatalogBuilder {
         Self {
             builders: HashMap::new(),
             bindings: MultiMap::new(),
-            chained_catalog: Some(chained_catalog.0.clone()),
+            reloadable: HashMap::new(),
+            chained_catalogs: smallvec::smallvec![chained_catalog.0.clone()],
+            shadowing: Shadowing::Disabled,
+        }
+    }
+
+    /// Creates a builder chained to multiple catalogs at once, e.g. to combine
+    /// a shared infrastructure catalog with a per-product catalog without
+    /// rebuilding either of them.
+    ///
+    /// Catalogs are looked up in the order they are specified. An interface
+    /// bound in several of them will be ambiguous for [`OneOf`] unless the
+    /// bindings come from a common ancestor catalog.
+    ///
+    /// Example:
+    /// ```
+    /// use dill::*;
+    ///
+    /// let infra = Catalog::builder().add_value(String::from("db")).build();
+    /// let product = Catalog::builder().add_value(42i32).build();
+    ///
+    /// let cat = CatalogBuilder::new_chained_multi(&[&infra, &product]).build();
+    ///
+    /// assert_eq!(cat.get_one::<String>().unwrap().as_str(), "db");
+    /// assert_eq!(*cat.get_one::<i32>().unwrap(), 42);
+    /// ```
+    pub fn new_chained_multi(chained_catalogs: &[&Catalog]) -> Self {
+        let mut b = Self::new();
+        for cat in chained_catalogs {
+            b.import_from(cat);
+        }
+        b
+    }
+
+    /// Chains the builder to one more catalog, which will be looked up after
+    /// all previously chained ones. See
+    /// [`CatalogBuilder::new_chained_multi()`].
+    pub fn import_from(&mut self, catalog: &Catalog) -> &mut Self {
+        if !self
+            .chained_catalogs
+            .iter()
+            .any(|c| Arc::ptr_eq(c, &catalog.0))
+        {
+            self.chained_catalogs.push(catalog.0.clone());
         }
+        self
+    }
+
+    /// Makes interfaces bound in this catalog shadow the bindings of the same
+    /// interfaces in the chained catalog, instead of making [`OneOf`]
+    /// resolution ambiguous. Both [`OneOf`] and [`AllOf`] will see only the
+    /// implementations from the nearest catalog.
+    ///
+    /// Example:
+    /// ```
+    /// use dill::*;
+    ///
+    /// trait Clock: Send + Sync {}
+    ///
+    /// #[component]
+    /// #[interface(dyn Clock)]
+    /// struct SystemClock;
+    /// impl Clock for SystemClock {}
+    ///
+    /// #[component]
+    /// #[interface(dyn Clock)]
+    /// struct FakeClock;
+    /// impl Clock for FakeClock {}
+    ///
+    /// let base = Catalog::builder().add::<SystemClock>().build();
+    /// let child = base.builder_chained().shadowing().add::<FakeClock>().build();
+    ///
+    /// assert!(child.get_one::<dyn Clock>().is_ok());
+    /// assert_eq!(child.get::<AllOf<dyn Clock>>().unwrap().len(), 1);
+    /// ```
+    pub fn shadowing(&mut self) -> &mut Self {
+        self.shadowing_mode(Shadowing::All)
+    }
+
+    /// Controls how bindings of this catalog interact with the bindings of
+    /// the chained catalog. See [`Shadowing`].
+    pub fn shadowing_mode(&mut self, mode: Shadowing) -> &mut Self {
+        self.shadowing = mode;
+        self
     }
 
     /// Registers a component using its associated builder.
@@ -56,6 +171,21 @@ impl CatalogBuilder {
         self
     }
 
+    /// Registers all declared instantiations of a generic component.
+    ///
+    /// Every instantiation is bound to the component's default interfaces
+    /// (with generic parameters substituted) just like [CatalogBuilder::add()]
+    /// does, so that e.g. `PostgresRepo<T>` declared with
+    /// `#[instantiate(User)]` and `#[instantiate(Order)]` satisfies both
+    /// `dyn Repository<User>` and `dyn Repository<Order>`.
+    pub fn add_family<F>(&mut self) -> &mut Self
+    where
+        F: ComponentFamily,
+    {
+        F::register(self);
+        self
+    }
+
     pub fn add_builder<Bld, Impl>(&mut self, builder: Bld) -> &mut Self
     where
         Impl: 'static + Send + Sync,
@@ -75,14 +205,7 @@ impl CatalogBuilder {
         // Bind implementation
         self.bindings.insert(
             IfaceTypeId(TypeId::of::<Impl>()),
-            Binding::new(
-                Arc::new(TypeCaster::<Impl> {
-                    // SAFETY: `TypeCaster<Iface>` is guaranteed to be invoked only on the `Impl`
-                    // instances
-                    cast_arc: |v| v.downcast().unwrap(),
-                }),
-                builder_arc.clone(),
-            ),
+            Binding::new(TypeCaster::<Impl>::of_impl(), builder_arc.clone()),
         );
 
         // To call the correct `TypedBuilder<Impl>::bind_interfaces()` method,
@@ -101,6 +224,22 @@ impl CatalogBuilder {
         self
     }
 
+    /// Registers a builder that can later be replaced via [`Catalog::reload()`]
+    /// without rebuilding the catalog, e.g. when the config file changes.
+    ///
+    /// Components that need to observe the reloaded instances should depend on
+    /// them via [`Reloadable`] handle. See its documentation for an example.
+    pub fn add_reloadable<Bld, Impl>(&mut self, builder: Bld) -> &mut Self
+    where
+        Impl: 'static + Send + Sync,
+        Bld: 'static + TypedBuilder<Impl>,
+    {
+        let builder = ReloadableBuilder::new(builder);
+        self.reloadable
+            .insert(ImplTypeId(TypeId::of::<Impl>()), Arc::new(builder.clone()));
+        self.add_builder(builder)
+    }
+
     /// Uses the provided factory once and caches the instance in a [Singleton]
     /// scope
     pub fn add_value_lazy<Fct, Impl>(&mut self, factory: Fct) -> &mut Self
@@ -112,6 +251,54 @@ impl CatalogBuilder {
         self
     }
 
+    /// Registers a function that creates instances of `Impl` in [Transient]
+    /// scope. Arguments of the function are resolved from the catalog based on
+    /// their types (see [`FactoryArg`]), e.g. `Arc<A>` as [`OneOf<A>`],
+    /// `Option<Arc<A>>` as [`Maybe<OneOf<A>>`] and `Vec<Arc<A>>` as
+    /// [`AllOf<A>`], and are reported as dependencies of the builder.
+    ///
+    /// Example:
+    /// ```
+    /// use std::sync::Arc;
+    /// use dill::*;
+    ///
+    /// struct Config {
+    ///     url: String,
+    /// }
+    ///
+    /// struct Client {
+    ///     url: String,
+    /// }
+    ///
+    /// let cat = Catalog::builder()
+    ///     .add_value(Config { url: "http://foo".to_owned() })
+    ///     .add_factory(|config: Arc<Config>| Client { url: config.url.clone() })
+    ///     .build();
+    ///
+    /// assert_eq!(cat.get_one::<Client>().unwrap().url, "http://foo");
+    /// ```
+    pub fn add_factory<Fct, Args, Impl>(&mut self, factory: Fct) -> &mut Self
+    where
+        Fct: FactoryFn<Args, Impl> + Send + Sync + 'static,
+        Args: 'static,
+        Impl: 'static + Send + Sync,
+    {
+        self.add_factory_scoped(Transient::new(), factory)
+    }
+
+    /// Same as [`CatalogBuilder::add_factory()`], but with the lifetime of
+    /// instances controlled by the specified [`Scope`], e.g.
+    /// `add_factory_scoped(Singleton::new(), |a: Arc<A>| B::new(a))`
+    pub fn add_factory_scoped<S, Fct, Args, Impl>(&mut self, scope: S, factory: Fct) -> &mut Self
+    where
+        S: Scope + Send + Sync + 'static,
+        Fct: FactoryFn<Args, Impl> + Send + Sync + 'static,
+        Args: 'static,
+        Impl: 'static + Send + Sync,
+    {
+        self.add_builder(FnBuilder::new(scope, factory))
+    }
+
     pub fn bind<Iface, Impl>(&mut self) -> &mut Self
     where
         Iface: 'static + ?Sized,
@@ -128,15 +315,7 @@ impl CatalogBuilder {
         self.bindings.insert(
             iface_type,
             Binding::new(
-                Arc::new(TypeCaster::<Iface> {
-                    cast_arc: |v| {
-                        // SAFETY: `TypeCaster<Iface>` is guaranteed to be invoked only on the
-                        // `Impl` instances
-                        let s: Arc<Impl> = v.downcast().unwrap();
-                        let t: Arc<Iface> = s;
-                        t
-                    },
-                }),
+                TypeCaster::<Iface>::of_iface::<Impl>(),
                 builder.unwrap().clone(),
             ),
         );
@@ -144,6 +323,66 @@ impl CatalogBuilder {
         self
     }
 
+    /// Makes the implementation of the `Source` interface available as `Iface`
+    /// by wrapping it into an `Adapter`, e.g. to keep `dyn StorageV1`
+    /// consumers working while migrating implementations to `dyn StorageV2`.
+    ///
+    /// A new adapter is created for every resolution. The adapter shows up in
+    /// introspection as an [`Agnostic`] builder of `Adapter` type that
+    /// implements `Iface` and depends on [`OneOf<Source>`], so validation will
+    /// report a missing or ambiguous `Source` just like for any component,
+    /// while consumers of any scope can inject the adapted interface.
+    ///
+    /// Example:
+    /// ```
+    /// use std::sync::Arc;
+    /// use dill::*;
+    ///
+    /// trait StorageV1: Send + Sync {
+    ///     fn get(&self) -> String;
+    /// }
+    ///
+    /// trait StorageV2: Send + Sync {
+    ///     fn read(&self, key: &str) -> String;
+    /// }
+    ///
+    /// #[component]
+    /// #[interface(dyn StorageV2)]
+    /// struct StorageImpl;
+    /// impl StorageV2 for StorageImpl {
+    ///     fn read(&self, key: &str) -> String {
+    ///         format!("v2::{key}")
+    ///     }
+    /// }
+    ///
+    /// struct StorageV1Adapter(Arc<dyn StorageV2>);
+    /// impl From<Arc<dyn StorageV2>> for StorageV1Adapter {
+    ///     fn from(inner: Arc<dyn StorageV2>) -> Self {
+    ///         Self(inner)
+    ///     }
+    /// }
+    /// impl StorageV1 for StorageV1Adapter {
+    ///     fn get(&self) -> String {
+    ///         self.0.read("default")
+    ///     }
+    /// }
+    ///
+    /// let cat = Catalog::builder()
+    ///     .add::<StorageImpl>()
+    ///     .adapt::<dyn StorageV1, dyn StorageV2, StorageV1Adapter>()
+    ///     .build();
+    ///
+    /// assert_eq!(cat.get_one::<dyn StorageV1>().unwrap().get(), "v2::default");
+    /// ```
+    pub fn adapt<Iface, Source, Adapter>(&mut self) -> &mut Self
+    where
+        Iface: 'static + ?Sized + Send + Sync,
+        Source: 'static + ?Sized + Send + Sync,
+        Adapter: 'static + Send + Sync + From<Arc<Source>> + Unsize<Iface>,
+    {
+        self.add_builder(AdapterBuilder::<Iface, Source, Adapter>::new())
+    }
+
     pub fn build(&mut self) -> Catalog {
         let mut builders = HashMap::new();
         let mut bindings = MultiMap::new();
@@ -152,7 +391,9 @@ impl CatalogBuilder {
         Catalog::new(Arc::new(CatalogImpl::new(
             builders,
             bindings,
-            self.chained_catalog.take(),
+            std::mem::take(&mut self.reloadable),
+            std::mem::take(&mut self.chained_catalogs),
+            self.shadowing,
         )))
     }
 
@@ -174,6 +415,51 @@ impl CatalogBuilder {
     ///  .unwrap();
     /// ```
     pub fn validate(&mut self) -> Result<(), ValidationError> {
+        let mut errors = Vec::new();
+        let mut validated = std::collections::HashSet::<TypeId>::new();
+
+        for b in self.builders.values() {
+            if !validated.insert(b.instance_type().id) {
+                continue;
+            }
+
+            self.validate_dependencies(Some(b.as_ref()), &b.dependencies_get_all(), &mut errors);
+        }
+
+        Self::validation_result(errors)
+    }
+
+    /// Validates that all arguments of the function can be resolved, e.g.
+    /// before invoking it via [`Catalog::call()`]. To validate against an
+    /// existing catalog use a chained builder.
+    ///
+    /// Example:
+    /// ```
+    /// use std::sync::Arc;
+    /// use dill::*;
+    ///
+    /// let cat = Catalog::builder().add_value(String::from("foo")).build();
+    ///
+    /// let job = |name: Arc<String>, count: Arc<usize>| name.repeat(*count);
+    ///
+    /// let err = cat.builder_chained().validate_call(&job).unwrap_err();
+    /// assert_eq!(err.errors.len(), 1);
+    /// ```
+    pub fn validate_call<Fct, Args, R>(&mut self, f: &Fct) -> Result<(), ValidationError>
+    where
+        Fct: FactoryFn<Args, R>,
+    {
+        let mut errors = Vec::new();
+        self.validate_dependencies(None, &f.dependencies_get_all(), &mut errors);
+        Self::validation_result(errors)
+    }
+
+    fn validate_dependencies(
+        &self,
+        b: Option<&dyn Builder>,
+        deps: &[DependencyInfo],
+        errors: &mut Vec<InjectionError>,
+    ) {
         const SCOPE_COMPAT: [TypeId; 4] = [
             TypeId::of::<Agnostic>(),
             TypeId::of::<Transient>(),
@@ -181,115 +467,195 @@ impl CatalogBuilder {
             TypeId::of::<Singleton>(),
         ];
 
-        let get_binding = |t: &IfaceTypeId| {
-            if let Some(v) = self.bindings.get(t) {
-                return Some(v);
+        for dep in deps {
+            if dep.is_bound {
+                // OK: provided explicitly
+                continue;
             }
 
-            let mut chained = self.chained_catalog.as_ref();
-            while let Some(c) = chained {
-                if let Some(v) = c.bindings.get(t) {
-                    return Some(v);
+            let dep_stack = || {
+                let root = InjectionContext::new_root();
+                let resolve = InjectionStackFrame::Resolve {
+                    spec: dep.spec,
+                    iface: dep.iface,
+                };
+                match b {
+                    Some(b) => root.push_build(b).push(resolve).to_stack(),
+                    None => root.push(resolve).to_stack(),
                 }
-                chained = c.chained_catalog.as_ref();
-            }
-            None
-        };
+            };
 
-        let mut errors = Vec::new();
-        let mut validated = std::collections::HashSet::<TypeId>::new();
-
-        for b in self.builders.values() {
-            let inst = b.instance_type();
-            let inst_scope = b.scope_type();
-
-            if validated.contains(&inst.id) {
+            if !dep.kind.checked {
+                // Custom specs that don't describe their resolution are not checked
                 continue;
             }
 
-            for dep in b.dependencies_get_all() {
-                if dep.is_bound {
-                    // OK: provided explicitly
-                } else if let Some(bind) = get_binding(&IfaceTypeId(dep.iface.id)) {
-                    let dep_scope = bind.builder.scope_type();
+            let view = if dep.kind.all {
+                BindingsView::AllOf
+            } else {
+                BindingsView::OneOf
+            };
+
+            let candidates = self.validation_candidates(&IfaceTypeId(dep.iface.id), view);
+
+            if candidates.len() > 1 && view == BindingsView::OneOf {
+                errors.push(InjectionError::Ambiguous(AmbiguousTypeError {
+                    dep_type: dep.iface,
+                    injection_stack: dep_stack(),
+                }));
+            } else if !candidates.is_empty() {
+                for candidate in &candidates {
+                    let dep_builder = candidate.builder.as_ref();
+                    let dep_scope = dep_builder.scope_type();
+
+                    if dep.kind.owned {
+                        // Ownership can be taken only of instances that are not cached,
+                        // and then they are safe to inject in any scope
+                        if dep_scope.id != TypeId::of::<Transient>()
+                            && dep_scope.id != TypeId::of::<Agnostic>()
+                        {
+                            errors.push(InjectionError::Ownership(OwnershipError {
+                                dep_type: dep_builder.instance_type(),
+                                injection_stack: dep_stack(),
+                            }));
+                        }
+                        continue;
+                    }
+
+                    if dep.kind.weak {
+                        // Weak references don't extend the lifetime of the instance, but
+                        // the instances that are not cached are dropped right after the
+                        // injection, unless the reference points to the instance under
+                        // construction that depends on this one
+                        let Some(b) = b else {
+                            continue;
+                        };
+                        let dep_lifetime = dep_builder.scope_lifetime();
+                        if (dep_lifetime.id == TypeId::of::<Transient>()
+                            || dep_lifetime.id == TypeId::of::<Agnostic>())
+                            && !self.depends_on(dep_builder, b, &mut Vec::new())
+                        {
+                            errors.push(InjectionError::ScopeInversion(Box::new(
+                                ScopeInversionError {
+                                    inst_type: b.instance_type(),
+                                    inst_scope: b.scope_type(),
+                                    inst_dep: *dep,
+                                    dep_type: dep_builder.instance_type(),
+                                    dep_scope,
+                                    injection_stack: dep_stack(),
+                                },
+                            )));
+                        }
+                        continue;
+                    }
 
                     if dep_scope.id == TypeId::of::<Agnostic>() {
                         // OK: Agnostic is safe to inject in any scope
                         continue;
                     }
 
-                    // TODO: Make scope compatibility checks more robust
-                    let i = SCOPE_COMPAT
-                        .iter()
-                        .position(|t| *t == inst_scope.id)
-                        .unwrap();
-                    let d = SCOPE_COMPAT
-                        .iter()
-                        .position(|t| *t == dep_scope.id)
-                        .unwrap();
+                    let scope_rank =
+                        |scope: &TypeInfo| SCOPE_COMPAT.iter().position(|t| *t == scope.id);
+
+                    // Custom scopes and callers that are not builders are not checked
+                    let Some((inst, inst_scope, inst_lifetime)) =
+                        b.map(|b| (b.instance_type(), b.scope_type(), b.scope_lifetime()))
+                    else {
+                        continue;
+                    };
+                    let (Some(i), Some(d)) = (
+                        scope_rank(&inst_lifetime),
+                        scope_rank(&dep_builder.scope_lifetime()),
+                    ) else {
+                        continue;
+                    };
 
                     if i > d {
                         let err = InjectionError::ScopeInversion(Box::new(ScopeInversionError {
                             inst_type: inst,
                             inst_scope,
-                            inst_dep: dep,
-                            dep_type: bind.builder.instance_type(),
+                            inst_dep: *dep,
+                            dep_type: dep_builder.instance_type(),
                             dep_scope,
-                            injection_stack: InjectionContext::new_root()
-                                .push_build(b.as_ref())
-                                .push(InjectionStackFrame::Resolve {
-                                    spec: dep.spec,
-                                    iface: dep.iface,
-                                })
-                                .to_stack(),
+                            injection_stack: dep_stack(),
                         }));
                         errors.push(err);
                     }
-                } else if dep.iface.id == TypeId::of::<Catalog>()
-                    || dep.iface.id == TypeId::of::<CatalogWeakRef>()
-                {
-                    // OK: self-injection of a catalog
-                } else {
-                    // TODO: Make spec identification more robust
-                    let spec = dep
-                        .spec
-                        .name
-                        .replace(dep.iface.name, "")
-                        .replace("dill::specs::", "");
-                    match spec.as_str() {
-                        "Maybe<OneOf<>>" | "AllOf<>" => {
-                            // OK: dependency is optional
-                        }
-                        _ => {
-                            let err = InjectionError::Unregistered(UnregisteredTypeError {
-                                dep_type: dep.iface,
-                                injection_stack: InjectionContext::new_root()
-                                    .push_build(b.as_ref())
-                                    .push(InjectionStackFrame::Resolve {
-                                        spec: dep.spec,
-                                        iface: dep.iface,
-                                    })
-                                    .to_stack(),
-                            });
-                            errors.push(err);
-                        }
-                    }
                 }
+            } else if dep.iface.id == TypeId::of::<Catalog>()
+                || dep.iface.id == TypeId::of::<CatalogWeakRef>()
+            {
+                // OK: self-injection of a catalog
+            } else if dep.kind.optional {
+                // OK: dependency is optional, its errors are handled by the
+                // component, or (for keys) it is provided at
+                // resolution time
+            } else {
+                let err = InjectionError::Unregistered(UnregisteredTypeError {
+                    dep_type: dep.iface,
+                    injection_stack: dep_stack(),
+                });
+                errors.push(err);
             }
+        }
+    }
+
+    /// Returns the candidates for the dependency. Mirrors the resolution
+    /// logic of `CatalogImpl::bindings_for()`
+    fn validation_candidates(&self, t: &IfaceTypeId, view: BindingsView) -> Vec<Binding> {
+        let own = self.bindings.get_vec(t).map_or(&[][..], Vec::as_slice);
+        let mut candidates = own.to_vec();
+        if !self.chained_catalogs.is_empty()
+            && (own.is_empty() || !self.shadowing.hides_parent(view))
+        {
+            let chained = CatalogImpl::resolve_chained_bindings(&self.chained_catalogs, *t);
+            candidates.extend(chained.get(view).iter().cloned());
+        }
+        candidates
+    }
 
-            validated.insert(inst.id);
+    /// Whether constructing `from` constructs `to` along the way, i.e. `from`
+    /// transitively depends on `to` via strong references
+    fn depends_on(&self, from: &dyn Builder, to: &dyn Builder, visited: &mut Vec<TypeId>) -> bool {
+        let from_type = from.instance_type().id;
+        if visited.contains(&from_type) {
+            return false;
         }
+        visited.push(from_type);
+
+        from.dependencies_get_all().iter().any(|dep| {
+            if dep.is_bound || !dep.kind.checked || dep.kind.weak {
+                return false;
+            }
+            let view = if dep.kind.all {
+                BindingsView::AllOf
+            } else {
+                BindingsView::OneOf
+            };
+            self.validation_candidates(&IfaceTypeId(dep.iface.id), view)
+                .iter()
+                .any(|c| {
+                    let c = c.builder.as_ref();
+                    c.instance_type().id == to.instance_type().id || self.depends_on(c, to, visited)
+                })
+        })
+    }
 
+    fn validation_result(mut errors: Vec<InjectionError>) -> Result<(), ValidationError> {
         // Sort and deduplicate by type
         errors.sort_by_key(|e| match e {
             InjectionError::Unregistered(err) => err.dep_type.id,
             InjectionError::Ambiguous(err) => err.dep_type.id,
             InjectionError::ScopeInversion(err) => err.dep_type.id,
+            InjectionError::Cycle(err) => err.dep_type.id,
+            InjectionError::Ownership(err) => err.dep_type.id,
         });
         errors.dedup_by_key(|e| match e {
             InjectionError::Unregistered(err) => err.dep_type.id,
             InjectionError::Ambiguous(err) => err.dep_type.id,
             InjectionError::ScopeInversion(err) => err.dep_type.id,
+            InjectionError::Cycle(err) => err.dep_type.id,
+            InjectionError::Ownership(err) => err.dep_type.id,
         });
 
         if !errors.is_empty() {
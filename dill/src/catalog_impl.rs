@@ -1,6 +1,6 @@
 use std::any::TypeId;
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use multimap::MultiMap;
 
@@ -16,34 +16,363 @@ pub(crate) struct ImplTypeId(pub TypeId);
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// [`TypeId`]s are already well-distributed hashes, so there is no need to
+/// hash them again
+#[derive(Default)]
+pub(crate) struct TypeIdHasher(u64);
+
+impl std::hash::Hasher for TypeIdHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 = self.0.rotate_left(8) ^ u64::from(*b);
+        }
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.0 ^= i;
+    }
+}
+
+pub(crate) type TypeIdHasherBuilder = std::hash::BuildHasherDefault<TypeIdHasher>;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Which of the bindings are visible to a resolution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BindingsView {
+    OneOf,
+    AllOf,
+}
+
+/// Bindings of an interface flattened over the chain of catalogs according to
+/// their [`Shadowing`] modes
 #[derive(Clone)]
+pub(crate) struct ResolvedBindings {
+    one_of: Arc<[Binding]>,
+    all_of: Arc<[Binding]>,
+}
+
+impl ResolvedBindings {
+    fn empty() -> &'static Self {
+        static EMPTY: OnceLock<ResolvedBindings> = OnceLock::new();
+        EMPTY.get_or_init(|| {
+            let empty: Arc<[Binding]> = Arc::new([]);
+            Self {
+                one_of: empty.clone(),
+                all_of: empty,
+            }
+        })
+    }
+
+    pub(crate) fn get(&self, view: BindingsView) -> &Arc<[Binding]> {
+        match view {
+            BindingsView::OneOf => &self.one_of,
+            BindingsView::AllOf => &self.all_of,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Slots of builders registered via [`CatalogBuilder::add_reloadable()`]
+/// holding [`ReloadableBuilder`]s of the corresponding types
+pub(crate) type ReloadableBuilders = HashMap<ImplTypeId, Arc<dyn std::any::Any + Send + Sync>>;
+
+/// Catalogs are usually chained to at most one parent
+pub(crate) type ChainedCatalogs = smallvec::SmallVec<[Arc<CatalogImpl>; 1]>;
+
 pub(crate) struct CatalogImpl {
     pub(crate) builders: HashMap<ImplTypeId, Arc<dyn Builder>>,
     pub(crate) bindings: MultiMap<IfaceTypeId, Binding>,
-    pub(crate) chained_catalog: Option<Arc<CatalogImpl>>,
+    pub(crate) reloadable: ReloadableBuilders,
+    pub(crate) chained_catalogs: ChainedCatalogs,
+    pub(crate) shadowing: Shadowing,
+
+    /// Values of an overlay catalog (see [`CatalogOverlayBuilder`])
+    pub(crate) values: OverlayValues,
+
+    /// Bindings of interfaces flattened over the entire chain of catalogs.
+    /// Contains a slot for every interface this catalog contributes bindings
+    /// to, so that the set of keys never changes after construction and
+    /// lookups don't need a lock. Slots of root catalogs are filled upon
+    /// construction, the rest upon first resolution of an interface.
+    /// Interfaces without a slot are resolved by the single chained catalog.
+    /// Overlay catalogs create the index only once something is chained to
+    /// them, as their own resolutions go through [`Self::candidates_for()`].
+    resolution_index: OnceLock<ResolutionIndex>,
 }
 
+type ResolutionIndex = HashMap<IfaceTypeId, OnceLock<ResolvedBindings>, TypeIdHasherBuilder>;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 impl CatalogImpl {
     pub fn new(
         builders: HashMap<ImplTypeId, Arc<dyn Builder>>,
         bindings: MultiMap<IfaceTypeId, Binding>,
-        chained_catalog: Option<Arc<CatalogImpl>>,
+        reloadable: ReloadableBuilders,
+        chained_catalogs: ChainedCatalogs,
+        shadowing: Shadowing,
     ) -> Self {
-        Self {
+        let this = Self {
             builders,
             bindings,
-            chained_catalog,
+            reloadable,
+            chained_catalogs,
+            shadowing,
+            values: OverlayValues::new(),
+            resolution_index: OnceLock::new(),
+        };
+
+        let index = this.resolution_index();
+        if this.chained_catalogs.is_empty() {
+            for (iface_type, slot) in index {
+                let _ = slot.set(this.resolve_bindings(*iface_type));
+            }
+        }
+
+        this
+    }
+
+    pub fn new_overlay(values: OverlayValues, chained_catalog: Arc<CatalogImpl>) -> Self {
+        Self {
+            builders: HashMap::new(),
+            bindings: MultiMap::new(),
+            reloadable: HashMap::new(),
+            chained_catalogs: smallvec::smallvec![chained_catalog],
+            shadowing: Shadowing::Disabled,
+            values,
+            resolution_index: OnceLock::new(),
         }
     }
 
+    /// Creates an empty catalog chained to multiple parents. Unlike
+    /// [`Self::new()`] the resolution index is created only upon first
+    /// resolution.
+    pub fn new_joined(chained_catalogs: ChainedCatalogs) -> Self {
+        Self {
+            builders: HashMap::new(),
+            bindings: MultiMap::new(),
+            reloadable: HashMap::new(),
+            chained_catalogs,
+            shadowing: Shadowing::Disabled,
+            values: OverlayValues::new(),
+            resolution_index: OnceLock::new(),
+        }
+    }
+
+    fn resolution_index(&self) -> &ResolutionIndex {
+        self.resolution_index
+            .get_or_init(|| self.new_resolution_index())
+    }
+
+    /// Creates empty slots for all interfaces bound in this catalog. Catalogs
+    /// chained to multiple parents also get slots for the interfaces bound in
+    /// any of the parents, as their bindings need to be merged.
+    fn new_resolution_index(&self) -> ResolutionIndex {
+        let own = |c: &CatalogImpl| {
+            c.bindings
+                .keys()
+                .copied()
+                .chain(c.values.iter().map(|v| IfaceTypeId(v.instance_type.id)))
+                .collect::<Vec<_>>()
+        };
+
+        let mut iface_types = own(self);
+        if self.chained_catalogs.len() > 1 {
+            for chained in self.chained_catalogs_deep(|_| true) {
+                iface_types.extend(own(chained));
+            }
+        }
+
+        iface_types
+            .into_iter()
+            .map(|iface_type| (iface_type, OnceLock::new()))
+            .collect()
+    }
+
+    pub fn find_value(&self, iface_type: IfaceTypeId) -> Option<&OverlayValue> {
+        self.values
+            .iter()
+            .find(|v| v.instance_type.id == iface_type.0)
+    }
+
+    /// Returns the overlay value and the bindings that can satisfy the
+    /// interface. Unlike [`Self::bindings_for()`] this does not materialize
+    /// overlay values into bindings, so resolving through a short-lived
+    /// overlay catalog doesn't allocate.
+    pub fn candidates_for(
+        &self,
+        iface_type: IfaceTypeId,
+        view: BindingsView,
+    ) -> (Option<&OverlayValue>, Arc<[Binding]>) {
+        match (self.values.is_empty(), &self.chained_catalogs[..]) {
+            (false, [chained_catalog]) => (
+                self.find_value(iface_type),
+                chained_catalog.bindings_for(iface_type, view),
+            ),
+            _ => (None, self.bindings_for(iface_type, view)),
+        }
+    }
+
+    /// Returns bindings of the interface from this and all chained catalogs,
+    /// in the order of lookup. After the first call for a certain interface
+    /// this performs no allocations and takes no locks.
+    pub fn bindings_for(&self, iface_type: IfaceTypeId, view: BindingsView) -> Arc<[Binding]> {
+        self.resolved_bindings(iface_type).get(view).clone()
+    }
+
+    fn resolved_bindings(&self, iface_type: IfaceTypeId) -> &ResolvedBindings {
+        match (
+            self.resolution_index().get(&iface_type),
+            &self.chained_catalogs[..],
+        ) {
+            (Some(slot), _) => slot.get_or_init(|| self.resolve_bindings(iface_type)),
+            // This catalog doesn't contribute any bindings
+            (None, [chained_catalog]) => chained_catalog.resolved_bindings(iface_type),
+            (None, _) => ResolvedBindings::empty(),
+        }
+    }
+
+    fn resolve_bindings(&self, iface_type: IfaceTypeId) -> ResolvedBindings {
+        let own = self
+            .bindings
+            .get_vec(&iface_type)
+            .map_or(&[][..], Vec::as_slice);
+        let own: std::borrow::Cow<'_, [Binding]> = match self.find_value(iface_type) {
+            None => own.into(),
+            Some(value) => std::iter::once(value.to_binding())
+                .chain(own.iter().cloned())
+                .collect::<Vec<_>>()
+                .into(),
+        };
+
+        let chained = match &self.chained_catalogs[..] {
+            [] => {
+                let own: Arc<[Binding]> = own.as_ref().into();
+                return ResolvedBindings {
+                    one_of: own.clone(),
+                    all_of: own,
+                };
+            }
+            chained_catalogs => Self::resolve_chained_bindings(chained_catalogs, iface_type),
+        };
+
+        // Share the index entry of the chained catalog
+        if own.is_empty() {
+            return chained;
+        }
+
+        let own_only: Arc<[Binding]> = own.as_ref().into();
+        let merge = |view: BindingsView| -> Arc<[Binding]> {
+            if self.shadowing.hides_parent(view) {
+                own_only.clone()
+            } else {
+                own.iter()
+                    .chain(chained.get(view).iter())
+                    .cloned()
+                    .collect()
+            }
+        };
+
+        let one_of = merge(BindingsView::OneOf);
+        let all_of = if Arc::ptr_eq(&chained.one_of, &chained.all_of)
+            && self.shadowing.hides_parent(BindingsView::OneOf)
+                == self.shadowing.hides_parent(BindingsView::AllOf)
+        {
+            one_of.clone()
+        } else {
+            merge(BindingsView::AllOf)
+        };
+
+        ResolvedBindings { one_of, all_of }
+    }
+
+    /// Returns the bindings of the interface visible through the chained
+    /// catalogs. Bindings of multiple parents are concatenated in their order,
+    /// skipping the bindings of catalogs reachable via several parents.
+    pub fn resolve_chained_bindings(
+        chained_catalogs: &[Arc<CatalogImpl>],
+        iface_type: IfaceTypeId,
+    ) -> ResolvedBindings {
+        if let [chained_catalog] = chained_catalogs {
+            return chained_catalog.resolved_bindings(iface_type).clone();
+        }
+
+        let resolved: Vec<_> = chained_catalogs
+            .iter()
+            .map(|c| c.resolved_bindings(iface_type))
+            .collect();
+
+        let merge = |view: BindingsView| -> Arc<[Binding]> {
+            let mut merged: Vec<Binding> = Vec::new();
+            for binding in resolved.iter().flat_map(|r| r.get(view).iter()) {
+                if !merged.iter().any(|b| b.is_same(binding)) {
+                    merged.push(binding.clone());
+                }
+            }
+            merged.into()
+        };
+
+        ResolvedBindings {
+            one_of: merge(BindingsView::OneOf),
+            all_of: merge(BindingsView::AllOf),
+        }
+    }
+
+    /// Returns all catalogs this catalog is chained to, directly or
+    /// transitively, in the order of lookup. Catalogs reachable via multiple
+    /// paths are returned once. Parents of a catalog are not visited if
+    /// `descend` returns `false` for it.
+    pub fn chained_catalogs_deep(
+        &self,
+        descend: impl Fn(&CatalogImpl) -> bool,
+    ) -> Vec<&Arc<CatalogImpl>> {
+        fn visit<'a>(
+            cat: &'a CatalogImpl,
+            descend: &impl Fn(&CatalogImpl) -> bool,
+            visited: &mut Vec<&'a Arc<CatalogImpl>>,
+        ) {
+            if !descend(cat) {
+                return;
+            }
+            for chained in &cat.chained_catalogs {
+                if !visited.iter().any(|v| Arc::ptr_eq(v, chained)) {
+                    visited.push(chained);
+                    visit(chained, descend, visited);
+                }
+            }
+        }
+
+        let mut visited = Vec::new();
+        visit(self, &descend, &mut visited);
+        visited
+    }
+
     pub fn builders<'a>(&'a self) -> Box<dyn Iterator<Item = &'a dyn Builder> + 'a> {
-        let it_builders = self.builders.values().map(|b| b.as_ref());
-        if let Some(chained_catalog) = &self.chained_catalog {
-            Box::new(it_builders.chain(chained_catalog.builders()))
+        let own_builders = |c: &'a CatalogImpl| {
+            c.values
+                .iter()
+                .map(|v| v as &dyn Builder)
+                .chain(c.builders.values().map(|b| b.as_ref()))
+        };
+
+        if self.chained_catalogs.is_empty() {
+            Box::new(own_builders(self))
         } else {
-            Box::new(it_builders)
+            Box::new(
+                std::iter::once(self)
+                    .chain(
+                        self.chained_catalogs_deep(|_| true)
+                            .into_iter()
+                            .map(|c| &**c),
+                    )
+                    .flat_map(own_builders),
+            )
         }
     }
 
@@ -54,13 +383,27 @@ impl CatalogImpl {
         Iface: 'static + ?Sized,
     {
         let iface_type = IfaceTypeId(TypeId::of::<Iface>());
-        let bindings = self.bindings.get_vec(&iface_type);
-        let it_bindings = TypecastBuilderIterator::new(bindings);
+        let own_bindings = move |c: &'a CatalogImpl| {
+            c.find_value(iface_type)
+                .map(TypecastBuilder::from_overlay_value)
+                .into_iter()
+                .chain(TypecastBuilderIterator::new(
+                    c.bindings.get_vec(&iface_type),
+                ))
+        };
 
-        if let Some(chained_catalog) = &self.chained_catalog {
-            Box::new(it_bindings.chain(chained_catalog.builders_for::<Iface>()))
+        if self.chained_catalogs.is_empty() {
+            Box::new(own_bindings(self))
         } else {
-            Box::new(it_bindings)
+            Box::new(
+                std::iter::once(self)
+                    .chain(
+                        self.chained_catalogs_deep(|c| !c.shadows(iface_type))
+                            .into_iter()
+                            .map(|c| &**c),
+                    )
+                    .flat_map(own_bindings),
+            )
         }
     }
 
@@ -73,15 +416,30 @@ impl CatalogImpl {
         Meta: 'static,
     {
         let iface_type = IfaceTypeId(TypeId::of::<Iface>());
-        let bindings = self.bindings.get_vec(&iface_type);
-
-        let it_bindings =
-            TypecastPredicateBuilderIterator::new(bindings, move |b| b.metadata_contains(pred));
+        let own_bindings = move |c: &'a CatalogImpl| {
+            TypecastPredicateBuilderIterator::new(c.bindings.get_vec(&iface_type), move |b| {
+                b.metadata_contains(pred)
+            })
+        };
 
-        if let Some(chained_catalog) = &self.chained_catalog {
-            Box::new(it_bindings.chain(chained_catalog.builders_for_with_meta::<Iface, Meta>(pred)))
+        if self.chained_catalogs.is_empty() {
+            Box::new(own_bindings(self))
         } else {
-            Box::new(it_bindings)
+            Box::new(
+                std::iter::once(self)
+                    .chain(
+                        self.chained_catalogs_deep(|c| !c.shadows(iface_type))
+                            .into_iter()
+                            .map(|c| &**c),
+                    )
+                    .flat_map(own_bindings),
+            )
         }
     }
+
+    /// Whether this catalog hides the bindings of the interface in chained
+    /// catalogs for the [`AllOf`] resolution
+    fn shadows(&self, iface_type: IfaceTypeId) -> bool {
+        self.shadowing.hides_parent(BindingsView::AllOf) && self.bindings.contains_key(&iface_type)
+    }
 }
@@ -0,0 +1,134 @@
+use std::any::{Any, TypeId, type_name};
+use std::sync::Arc;
+
+use smallvec::SmallVec;
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Builds a lightweight overlay [`Catalog`] that adds a handful of values on
+/// top of an existing catalog.
+///
+/// Overlays are intended for short-lived catalogs, like ones created per HTTP
+/// request to hold the caller's identity and a [`TransactionCache`]. Unlike
+/// [`Catalog::builder_chained()`] they store values inline and avoid maps and
+/// per-value builder allocations, but values can only be resolved by their own
+/// types - they cannot be bound to interfaces.
+///
+/// The resulting catalog behaves the same as a chained catalog for resolution,
+/// [`Catalog::weak_ref()`] and [`Lazy`] injection.
+///
+/// Example:
+/// ```
+/// let base = dill::Catalog::builder().add_value(1i32).build();
+///
+/// let request_catalog = base
+///     .builder_overlay()
+///     .add_value(String::from("bob"))
+///     .add_value(dill::scopes::TransactionCache::new())
+///     .build();
+///
+/// assert_eq!(*request_catalog.get_one::<i32>().unwrap(), 1);
+/// assert_eq!(request_catalog.get_one::<String>().unwrap().as_str(), "bob");
+/// ```
+pub struct CatalogOverlayBuilder {
+    chained_catalog: Arc<CatalogImpl>,
+    values: OverlayValues,
+}
+
+/// Overlays typically hold just a couple of values, so we store them inline
+pub(crate) type OverlayValues = SmallVec<[OverlayValue; 4]>;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl CatalogOverlayBuilder {
+    pub fn new(chained_catalog: &Catalog) -> Self {
+        Self {
+            chained_catalog: chained_catalog.0.clone(),
+            values: SmallVec::new(),
+        }
+    }
+
+    pub fn add_value<Impl>(&mut self, value: Impl) -> &mut Self
+    where
+        Impl: 'static + Send + Sync,
+    {
+        let type_id = TypeId::of::<Impl>();
+        if self.values.iter().any(|v| v.instance_type.id == type_id) {
+            panic!(
+                "Value of type {} is already registered",
+                type_name::<Impl>()
+            );
+        }
+
+        self.values.push(OverlayValue::new(Arc::new(value)));
+        self
+    }
+
+    pub fn build(&mut self) -> Catalog {
+        Catalog::new(Arc::new(CatalogImpl::new_overlay(
+            std::mem::take(&mut self.values),
+            self.chained_catalog.clone(),
+        )))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A value stored inline in the overlay catalog that acts as its own builder
+pub(crate) struct OverlayValue {
+    instance: Arc<dyn Any + Send + Sync>,
+    pub(crate) instance_type: TypeInfo,
+    pub(crate) caster: &'static AnyTypeCaster,
+    into_builder: fn(&Arc<dyn Any + Send + Sync>) -> Arc<dyn Builder>,
+}
+
+impl OverlayValue {
+    fn new<Impl>(instance: Arc<Impl>) -> Self
+    where
+        Impl: 'static + Send + Sync,
+    {
+        Self {
+            instance,
+            instance_type: TypeInfo::of::<Impl>(),
+            caster: TypeCaster::<Impl>::of_impl(),
+            into_builder: |inst| {
+                let inst: Arc<Impl> = inst.clone().downcast().unwrap();
+                Arc::new(inst)
+            },
+        }
+    }
+
+    /// Converts value into a regular binding that can be stored in the
+    /// resolution index of catalogs chained on top of this overlay
+    pub(crate) fn to_binding(&self) -> Binding {
+        Binding::new(self.caster, (self.into_builder)(&self.instance))
+    }
+}
+
+impl Builder for OverlayValue {
+    fn instance_type(&self) -> TypeInfo {
+        self.instance_type
+    }
+
+    fn scope_type(&self) -> TypeInfo {
+        TypeInfo::of::<crate::scopes::Singleton>()
+    }
+
+    fn interfaces(&self, _clb: &mut dyn FnMut(&TypeInfo) -> bool) {}
+
+    fn dependencies(&self, _clb: &mut dyn FnMut(&DependencyInfo) -> bool) {}
+
+    fn metadata<'a>(&'a self, _clb: &mut dyn FnMut(&'a dyn Any) -> bool) {}
+
+    fn get_any(
+        &self,
+        _cat: &Catalog,
+        _ctx: &InjectionContext,
+    ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError> {
+        Ok(self.instance.clone())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
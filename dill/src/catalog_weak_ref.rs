@@ -1,4 +1,4 @@
-use std::sync::{Arc, Weak};
+use std::sync::{Arc, Mutex, Weak};
 
 use crate::*;
 
@@ -11,9 +11,21 @@ use crate::*;
 ///
 /// When used with chained catalogs, weak references will be stored to all
 /// catalogs in the chain and the first catalog that is still alive will be used
-/// for instantiation. It is up to you to keep the catalog instances alive.
+/// for instantiation. Catalogs chained to multiple parents are followed by
+/// their parents in the lookup order, and once such a catalog is dropped all
+/// of its surviving parents remain visible. It is up to you to keep the catalog
+/// instances alive.
 #[derive(Clone)]
-pub struct CatalogWeakRef(Arc<[std::sync::Weak<CatalogImpl>]>);
+pub struct CatalogWeakRef(Arc<CatalogWeakRefState>);
+
+struct CatalogWeakRefState {
+    /// The referenced catalog followed by all catalogs in its chain
+    catalogs: Vec<Weak<CatalogImpl>>,
+
+    /// Catalog used once the referenced one is dropped. Held weakly, as
+    /// otherwise the weak reference would keep the surviving catalogs alive.
+    fallback: Mutex<Weak<CatalogImpl>>,
+}
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -21,35 +33,58 @@ impl CatalogWeakRef {
     pub(crate) fn new(pimpl: &std::sync::Arc<CatalogImpl>) -> Self {
         // We keep weak references to all catalogs in the chain and will use the first
         // one that is still alive
+        let catalogs = std::iter::once(pimpl)
+            .chain(pimpl.chained_catalogs_deep(|_| true))
+            .map(Arc::downgrade)
+            .collect();
+
+        Self(Arc::new(CatalogWeakRefState {
+            catalogs,
+            fallback: Mutex::new(Weak::new()),
+        }))
+    }
 
-        // Count depth
-        let mut len = 0;
-        let mut current = Some(pimpl);
-        while let Some(p) = current {
-            len += 1;
-            current = p.chained_catalog.as_ref();
+    pub fn upgrade(&self) -> Catalog {
+        // Live catalog keeps all of its chained catalogs alive
+        if let Some(head) = self.0.catalogs[0].upgrade() {
+            return Catalog::new(head);
         }
 
-        // Fill buffer
-        let mut refs = Vec::with_capacity(len);
-        let mut current = Some(pimpl);
-        while let Some(p) = current {
-            refs.push(Arc::downgrade(p));
-            current = p.chained_catalog.as_ref();
+        // Catalogs that are dropped stay dropped, so the fallback remains valid
+        // for as long as someone holds it
+        let mut fallback = self.0.fallback.lock().unwrap();
+        if let Some(head) = fallback.upgrade() {
+            return Catalog::new(head);
         }
 
-        Self(refs.into())
+        let head = self.surviving_head();
+        *fallback = Arc::downgrade(&head);
+        Catalog::new(head)
     }
 
-    pub fn upgrade(&self) -> Catalog {
-        // Use the first catalog from the chain that is still alive
-        for p in self.0.as_ref() {
-            if let Some(pimpl) = Weak::upgrade(p) {
-                return Catalog::new(pimpl);
-            }
+    /// Returns the catalog that sees all surviving catalogs in the chain
+    fn surviving_head(&self) -> Arc<CatalogImpl> {
+        let alive: Vec<_> = self.0.catalogs.iter().filter_map(Weak::upgrade).collect();
+
+        // Live catalogs keep their chained catalogs alive too, so the catalogs
+        // not chained directly to another live one are the only heads
+        let mut heads: ChainedCatalogs = alive
+            .iter()
+            .filter(|c| {
+                !alive
+                    .iter()
+                    .any(|other| other.chained_catalogs.iter().any(|p| Arc::ptr_eq(p, c)))
+            })
+            .cloned()
+            .collect();
+
+        match heads.len() {
+            0 => panic!("Catalog was already dropped"),
+            1 => heads.pop().unwrap(),
+            // A catalog chained to multiple parents was dropped - join all of
+            // the surviving parents in an empty catalog
+            _ => Arc::new(CatalogImpl::new_joined(heads)),
         }
-
-        panic!("Catalog was already dropped")
     }
 
     #[inline(always)]
@@ -86,7 +121,7 @@ impl CatalogWeakRef {
 
 impl std::fmt::Debug for CatalogWeakRef {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for p in self.0.as_ref() {
+        for p in &self.0.catalogs {
             if let Some(p) = p.upgrade() {
                 return write!(
                     f,
@@ -0,0 +1,108 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::mem::MaybeUninit;
+use std::sync::{Arc, UniqueArc, Weak};
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+thread_local! {
+    /// Instances that are currently being constructed on this thread
+    static UNDER_CONSTRUCTION: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+}
+
+struct Frame {
+    /// Points to the `Weak<T>` owned by the [`construct()`] call that pushed
+    /// the frame
+    weak: *const dyn Any,
+}
+
+/// Pops the frame even when construction unwinds
+struct FrameGuard;
+
+impl FrameGuard {
+    fn push<T: 'static>(weak: &Weak<T>) -> Self {
+        UNDER_CONSTRUCTION.with_borrow_mut(|frames| {
+            frames.push(Frame {
+                weak: weak as &dyn Any,
+            })
+        });
+        Self
+    }
+}
+
+impl Drop for FrameGuard {
+    fn drop(&mut self) {
+        UNDER_CONSTRUCTION.with_borrow_mut(|frames| frames.pop());
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Constructs a new instance, allowing its dependencies to receive a
+/// [`Weak`] reference to it while it's being built.
+///
+/// Similarly to [`Arc::new_cyclic()`], the memory for the instance is
+/// allocated up front, so the reference cannot be upgraded until the
+/// construction is finished, but unlike it the construction is allowed to
+/// fail, in which case the reference stays dangling.
+///
+/// Used by the code generated by the `#[component]` macro.
+pub fn construct<T>(
+    build: impl FnOnce() -> Result<T, InjectionError>,
+) -> Result<Arc<T>, InjectionError>
+where
+    T: 'static + Send + Sync,
+{
+    let mut uninit = UniqueArc::new(MaybeUninit::<T>::uninit());
+
+    // SAFETY: `MaybeUninit<T>` has the same size and alignment as `T`, and the
+    // reference cannot be upgraded until the `UniqueArc` is converted into `Arc`
+    // below, which happens only once the instance is initialized
+    let weak: Weak<T> =
+        unsafe { Weak::from_raw(Weak::into_raw(UniqueArc::downgrade(&uninit)).cast()) };
+
+    let inst = {
+        let _guard = FrameGuard::push(&weak);
+        build()?
+    };
+
+    uninit.write(inst);
+
+    // SAFETY: initialized above
+    Ok(unsafe { UniqueArc::into_arc(uninit).assume_init() })
+}
+
+/// Returns a [`Weak`] reference to the instance implementing `Iface` if it's
+/// currently being constructed on this thread, or `None` otherwise.
+///
+/// Instances are matched by their own type and by all interfaces their
+/// builders are bound to in the catalog.
+pub(crate) fn weak_under_construction<Iface>(cat: &Catalog) -> Option<Weak<Iface>>
+where
+    Iface: 'static + ?Sized,
+{
+    UNDER_CONSTRUCTION.with_borrow(|frames| {
+        if frames.is_empty() {
+            return None;
+        }
+
+        let (_, bindings) = cat.0.candidates_for(
+            IfaceTypeId(std::any::TypeId::of::<Iface>()),
+            BindingsView::AllOf,
+        );
+
+        frames.iter().rev().find_map(|frame| {
+            // SAFETY: frames are popped before the references they point to go
+            // out of scope
+            let weak = unsafe { &*frame.weak };
+
+            weak.downcast_ref::<Weak<Iface>>().cloned().or_else(|| {
+                bindings
+                    .iter()
+                    .find_map(|b| TypecastBuilder::<Iface>::from_binding(b).cast_weak(weak))
+            })
+        })
+    })
+}
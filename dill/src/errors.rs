@@ -14,6 +14,10 @@ pub enum InjectionError {
     Ambiguous(AmbiguousTypeError),
     #[error(transparent)]
     ScopeInversion(Box<ScopeInversionError>),
+    #[error(transparent)]
+    Cycle(CycleError),
+    #[error(transparent)]
+    Ownership(OwnershipError),
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -33,6 +37,20 @@ impl InjectionError {
             injection_stack: ctx.to_stack(),
         })
     }
+
+    pub fn cycle(dep_type: TypeInfo, ctx: &InjectionContext) -> Self {
+        Self::Cycle(CycleError {
+            dep_type,
+            injection_stack: ctx.to_stack(),
+        })
+    }
+
+    pub fn ownership<Impl: 'static + ?Sized>(ctx: &InjectionContext) -> Self {
+        Self::Ownership(OwnershipError {
+            dep_type: TypeInfo::of::<Impl>(),
+            injection_stack: ctx.to_stack(),
+        })
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -91,6 +109,40 @@ impl std::fmt::Display for ScopeInversionError {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(Error, Debug, Clone)]
+pub struct CycleError {
+    pub dep_type: TypeInfo,
+    pub injection_stack: InjectionStack,
+}
+
+impl std::fmt::Display for CycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Dependency cycle: {}", self.dep_type.name)?;
+        write!(f, "Injection stack:\n{}", self.injection_stack)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Error, Debug, Clone)]
+pub struct OwnershipError {
+    pub dep_type: TypeInfo,
+    pub injection_stack: InjectionStack,
+}
+
+impl std::fmt::Display for OwnershipError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Cannot take ownership of a shared instance: {}",
+            self.dep_type.name
+        )?;
+        write!(f, "Injection stack:\n{}", self.injection_stack)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[derive(Error, Debug, Clone)]
 pub struct ValidationError {
     pub errors: Vec<InjectionError>,
@@ -119,6 +171,8 @@ impl ValidationErrorExt for Result<(), ValidationError> {
             InjectionError::Unregistered(e) => e.dep_type.id != type_id,
             InjectionError::Ambiguous(e) => e.dep_type.id != type_id,
             InjectionError::ScopeInversion(e) => e.dep_type.id != type_id,
+            InjectionError::Cycle(e) => e.dep_type.id != type_id,
+            InjectionError::Ownership(e) => e.dep_type.id != type_id,
         });
 
         if err.errors.is_empty() {
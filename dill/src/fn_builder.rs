@@ -0,0 +1,222 @@
+use std::any::Any;
+use std::marker::PhantomData;
+use std::sync::{Arc, Weak};
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Maps the type of a factory function argument to the [`DependencySpec`]
+/// used to resolve it, see [`CatalogBuilder::add_factory()`]
+pub trait FactoryArg: Sized {
+    type Spec: DependencySpec<ReturnType = Self> + 'static;
+}
+
+impl<T> FactoryArg for Arc<T>
+where
+    T: 'static + ?Sized + Send + Sync,
+{
+    type Spec = OneOf<T>;
+}
+
+impl<A> FactoryArg for Option<A>
+where
+    A: FactoryArg,
+{
+    type Spec = Maybe<A::Spec>;
+}
+
+impl<T> FactoryArg for Vec<Arc<T>>
+where
+    T: 'static + ?Sized + Send + Sync,
+{
+    type Spec = AllOf<T>;
+}
+
+impl<A> FactoryArg for crate::lazy::Lazy<A>
+where
+    A: FactoryArg,
+{
+    type Spec = specs::Lazy<A::Spec>;
+}
+
+impl<A> FactoryArg for crate::reloadable::Reloadable<A>
+where
+    A: FactoryArg,
+{
+    type Spec = specs::Reloadable<A::Spec>;
+}
+
+impl<A> FactoryArg for Result<A, InjectionError>
+where
+    A: FactoryArg,
+{
+    type Spec = Fallible<A::Spec>;
+}
+
+impl<T> FactoryArg for Box<T>
+where
+    T: 'static + ?Sized + Send + Sync,
+{
+    type Spec = OwnedBox<T>;
+}
+
+impl<T> FactoryArg for Weak<T>
+where
+    T: 'static + ?Sized + Send + Sync,
+{
+    type Spec = WeakOf<T>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Function whose arguments are resolved from the catalog, implemented for
+/// functions and closures of up to 8 [`FactoryArg`] arguments.
+///
+/// See [`CatalogBuilder::add_factory()`] and [`Catalog::call()`].
+pub trait FactoryFn<Args, Impl> {
+    /// Resolves the arguments and calls the function
+    fn call(&self, cat: &Catalog, ctx: &InjectionContext) -> Result<Impl, InjectionError>;
+
+    /// Lists the dependencies of the function
+    fn dependencies(&self, clb: &mut dyn FnMut(&DependencyInfo) -> bool);
+
+    /// Returns all dependencies of the function
+    fn dependencies_get_all(&self) -> Vec<DependencyInfo> {
+        let mut ret = Vec::new();
+        self.dependencies(&mut |dep| {
+            ret.push(*dep);
+            true
+        });
+        ret
+    }
+}
+
+macro_rules! impl_factory_fn {
+    ($($arg:ident),*) => {
+        impl<Fct, Impl, $($arg,)*> FactoryFn<($($arg,)*), Impl> for Fct
+        where
+            Fct: Fn($($arg),*) -> Impl,
+            $(
+                $arg: FactoryArg,
+                <$arg::Spec as DependencySpec>::IfaceType: 'static,
+            )*
+        {
+            #[allow(non_snake_case, unused_variables)]
+            fn call(&self, cat: &Catalog, ctx: &InjectionContext) -> Result<Impl, InjectionError> {
+                $(
+                    let $arg = cat.get_with_context::<$arg::Spec>(ctx)?;
+                )*
+                Ok(self($($arg),*))
+            }
+
+            #[allow(unused_variables)]
+            fn dependencies(&self, clb: &mut dyn FnMut(&DependencyInfo) -> bool) {
+                $(
+                    if !clb(&DependencyInfo::of::<<$arg::Spec as DependencySpec>::IfaceType, $arg::Spec>()) {
+                        return;
+                    }
+                )*
+            }
+        }
+    };
+}
+
+impl_factory_fn!();
+impl_factory_fn!(A1);
+impl_factory_fn!(A1, A2);
+impl_factory_fn!(A1, A2, A3);
+impl_factory_fn!(A1, A2, A3, A4);
+impl_factory_fn!(A1, A2, A3, A4, A5);
+impl_factory_fn!(A1, A2, A3, A4, A5, A6);
+impl_factory_fn!(A1, A2, A3, A4, A5, A6, A7);
+impl_factory_fn!(A1, A2, A3, A4, A5, A6, A7, A8);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Builder that creates instances using a [`FactoryFn`], with the lifetime
+/// controlled by the [`Scope`]
+pub(crate) struct FnBuilder<Fct, Args, Impl, S> {
+    factory: Fct,
+    scope: S,
+    _dummy: PhantomData<fn(Args) -> Impl>,
+}
+
+impl<Fct, Args, Impl, S> FnBuilder<Fct, Args, Impl, S>
+where
+    Fct: FactoryFn<Args, Impl> + Send + Sync + 'static,
+    Impl: 'static + Send + Sync,
+    S: Scope + Send + Sync + 'static,
+{
+    pub(crate) fn new(scope: S, factory: Fct) -> Self {
+        Self {
+            factory,
+            scope,
+            _dummy: PhantomData,
+        }
+    }
+}
+
+impl<Fct, Args, Impl, S> Builder for FnBuilder<Fct, Args, Impl, S>
+where
+    Fct: FactoryFn<Args, Impl> + Send + Sync + 'static,
+    Impl: 'static + Send + Sync,
+    S: Scope + Send + Sync + 'static,
+{
+    fn instance_type(&self) -> TypeInfo {
+        TypeInfo::of::<Impl>()
+    }
+
+    fn scope_type(&self) -> TypeInfo {
+        TypeInfo::of::<S>()
+    }
+
+    fn scope_lifetime(&self) -> TypeInfo {
+        S::lifetime()
+    }
+
+    fn interfaces(&self, _clb: &mut dyn FnMut(&TypeInfo) -> bool) {}
+
+    fn dependencies(&self, clb: &mut dyn FnMut(&DependencyInfo) -> bool) {
+        self.factory.dependencies(clb);
+    }
+
+    fn metadata<'a>(&'a self, _clb: &mut dyn FnMut(&'a dyn Any) -> bool) {}
+
+    fn get_any(
+        &self,
+        cat: &Catalog,
+        ctx: &InjectionContext,
+    ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError> {
+        Ok(TypedBuilder::get_with_context(self, cat, ctx)?)
+    }
+
+    fn evict(&self, cat: &Catalog) {
+        self.scope.evict(cat);
+    }
+}
+
+impl<Fct, Args, Impl, S> TypedBuilder<Impl> for FnBuilder<Fct, Args, Impl, S>
+where
+    Fct: FactoryFn<Args, Impl> + Send + Sync + 'static,
+    Impl: 'static + Send + Sync,
+    S: Scope + Send + Sync + 'static,
+{
+    fn get_with_context(
+        &self,
+        cat: &Catalog,
+        ctx: &InjectionContext,
+    ) -> Result<Arc<Impl>, InjectionError> {
+        let inst = self
+            .scope
+            .get_or_create(cat, ctx, TypeInfo::of::<Impl>(), || {
+                let ctx = ctx.push_build(self);
+                let inst = crate::cyclic::construct(|| self.factory.call(cat, &ctx))?;
+                Ok(inst)
+            })?;
+
+        Ok(inst.downcast().unwrap())
+    }
+
+    fn bind_interfaces(&self, _cat: &mut CatalogBuilder) {}
+}
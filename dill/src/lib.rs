@@ -1,3 +1,4 @@
+#![feature(unique_rc_arc)]
 #![feature(unsize)]
 
 //! Runtime dependency injection.
@@ -162,29 +163,42 @@
 //! assert_eq!(inst.url(), "http://foo:8080");
 //! ```
 
+mod adapter;
 mod builder;
 pub mod cache;
 mod catalog;
 mod catalog_builder;
 mod catalog_impl;
+mod catalog_overlay;
 mod catalog_weak_ref;
+pub mod cyclic;
 mod errors;
+mod fn_builder;
 mod injection_context;
 mod lazy;
+pub mod registry;
+mod reloadable;
 pub mod scopes;
 pub mod specs;
 mod typecast_builder;
 pub mod utils;
 
+pub(crate) use adapter::AdapterBuilder;
 pub use builder::*;
 pub use catalog::Catalog;
-pub use catalog_builder::CatalogBuilder;
+pub use catalog_builder::{CatalogBuilder, Shadowing};
 pub(crate) use catalog_impl::*;
+pub use catalog_overlay::CatalogOverlayBuilder;
+pub(crate) use catalog_overlay::{OverlayValue, OverlayValues};
 pub use catalog_weak_ref::*;
 pub use dill_impl::*;
 pub use errors::*;
+pub(crate) use fn_builder::FnBuilder;
+pub use fn_builder::{FactoryArg, FactoryFn};
 pub use injection_context::*;
 pub use lazy::Lazy;
+pub use reloadable::Reloadable;
+pub(crate) use reloadable::ReloadableBuilder;
 pub use scopes::*;
 pub use specs::*;
 pub use typecast_builder::*;
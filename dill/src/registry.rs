@@ -0,0 +1,89 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+#[doc(hidden)]
+pub use inventory;
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Interface of a component declared by annotating the trait `impl` block with
+/// the `#[implements]` attribute.
+///
+/// Declarations are collected from all linked crates at startup and are picked
+/// up by the builders generated by the `#[component]` and `#[provider]` macros
+/// as the default interfaces of the component. Interfaces that are also listed
+/// in `#[interface(...)]` are bound only once.
+///
+/// Collection relies on the [`inventory`] crate, so on targets it doesn't
+/// support (e.g. `wasm32-unknown-unknown`) the declarations are silently
+/// ignored - use `#[interface(...)]` on the component there instead.
+pub struct Implementation {
+    pub impl_type: fn() -> TypeInfo,
+    pub iface: fn() -> TypeInfo,
+    pub bind: fn(&mut CatalogBuilder),
+}
+
+inventory::collect!(Implementation);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+type ImplementationsIndex = HashMap<TypeId, Vec<&'static Implementation>, TypeIdHasherBuilder>;
+
+/// Returns the declared implementations of the type, ordered by the interface
+/// name. After the index is built upon the first call, this is a single hash
+/// lookup, which is skipped entirely when nothing was declared.
+fn implementations_of(type_id: TypeId) -> &'static [&'static Implementation] {
+    static INDEX: OnceLock<ImplementationsIndex> = OnceLock::new();
+
+    let index = INDEX.get_or_init(|| {
+        let mut index = ImplementationsIndex::default();
+        for imp in inventory::iter::<Implementation> {
+            index.entry((imp.impl_type)().id).or_default().push(imp);
+        }
+        for imps in index.values_mut() {
+            imps.sort_by_key(|imp| (imp.iface)().name);
+        }
+        index
+    });
+
+    if index.is_empty() {
+        return &[];
+    }
+    index.get(&type_id).map_or(&[], Vec::as_slice)
+}
+
+/// Returns the declared implementations of `Impl`, skipping the interfaces
+/// that the builder already declares itself
+fn undeclared_implementations<Impl: 'static>(
+    declared: &[TypeInfo],
+) -> impl Iterator<Item = &'static Implementation> + '_ {
+    implementations_of(TypeId::of::<Impl>())
+        .iter()
+        .copied()
+        .filter(|imp| !declared.contains(&(imp.iface)()))
+}
+
+/// Lists the interfaces declared for `Impl` via `#[implements]`, except the
+/// `declared` ones.
+///
+/// Used by the code generated by the `#[component]` macro.
+pub fn interfaces<Impl: 'static>(declared: &[TypeInfo], clb: &mut dyn FnMut(&TypeInfo) -> bool) {
+    for imp in undeclared_implementations::<Impl>(declared) {
+        if !clb(&(imp.iface)()) {
+            return;
+        }
+    }
+}
+
+/// Binds `Impl` to all interfaces declared via `#[implements]`, except the
+/// `declared` ones.
+///
+/// Used by the code generated by the `#[component]` macro.
+pub fn bind_interfaces<Impl: 'static>(declared: &[TypeInfo], cat: &mut CatalogBuilder) {
+    for imp in undeclared_implementations::<Impl>(declared) {
+        (imp.bind)(cat);
+    }
+}
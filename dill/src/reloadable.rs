@@ -0,0 +1,180 @@
+use std::any::Any;
+use std::sync::{Arc, RwLock};
+
+use crate::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A handle that always returns the current instance of a dependency, even if
+/// its builder was replaced via [`Catalog::reload()`] after the handle was
+/// injected.
+///
+/// Unlike [`Lazy`] it only holds a [`CatalogWeakRef`], so it's safe to keep in
+/// long-lived components like those in [`Singleton`] scope. Same as with
+/// [`Lazy`], avoid holding on to the returned instances to let the old ones be
+/// dropped after reload.
+///
+/// ### Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use dill::*;
+///
+/// struct Config {
+///     level: u32,
+/// }
+///
+/// #[component]
+/// #[scope(Singleton)]
+/// struct Service {
+///     config: Reloadable<Arc<Config>>,
+/// }
+///
+/// let cat = Catalog::builder()
+///     .add_reloadable(Arc::new(Config { level: 1 }))
+///     .add::<Service>()
+///     .build();
+///
+/// let svc = cat.get_one::<Service>().unwrap();
+/// assert_eq!(svc.config.get().unwrap().level, 1);
+///
+/// // Config file has changed
+/// cat.reload::<Config>(Arc::new(Config { level: 2 })).unwrap();
+/// assert_eq!(svc.config.get().unwrap().level, 2);
+/// ```
+pub struct Reloadable<T> {
+    catalog: CatalogWeakRef,
+    resolve: fn(&Catalog) -> Result<T, InjectionError>,
+}
+
+impl<T> Clone for Reloadable<T> {
+    fn clone(&self) -> Self {
+        Self {
+            catalog: self.catalog.clone(),
+            resolve: self.resolve,
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Reloadable<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Reloadable").finish_non_exhaustive()
+    }
+}
+
+impl<T> Reloadable<T> {
+    pub(crate) fn new(
+        catalog: CatalogWeakRef,
+        resolve: fn(&Catalog) -> Result<T, InjectionError>,
+    ) -> Self {
+        Self { catalog, resolve }
+    }
+
+    /// Resolves the current instance
+    pub fn get(&self) -> Result<T, InjectionError> {
+        (self.resolve)(&self.catalog.upgrade())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// A slot registered via [`CatalogBuilder::add_reloadable()`] that delegates to
+/// the current builder, which can be replaced via [`Catalog::reload()`].
+///
+/// Interface bindings are established upon registration and are not affected
+/// by the reload. Since the builder can be replaced at any time, the metadata
+/// of reloadable builders is not available for introspection.
+pub(crate) struct ReloadableBuilder<Impl: ?Sized> {
+    current: Arc<RwLock<Arc<dyn TypedBuilder<Impl>>>>,
+}
+
+impl<Impl> Clone for ReloadableBuilder<Impl>
+where
+    Impl: ?Sized,
+{
+    fn clone(&self) -> Self {
+        Self {
+            current: self.current.clone(),
+        }
+    }
+}
+
+impl<Impl> ReloadableBuilder<Impl>
+where
+    Impl: 'static + Send + Sync,
+{
+    pub(crate) fn new(builder: impl TypedBuilder<Impl> + 'static) -> Self {
+        Self {
+            current: Arc::new(RwLock::new(Arc::new(builder))),
+        }
+    }
+
+    /// Replaces the builder. Instances created by the previous builder will be
+    /// dropped once no one holds them.
+    pub(crate) fn reload(&self, builder: impl TypedBuilder<Impl> + 'static) {
+        let prev = std::mem::replace(&mut *self.current.write().unwrap(), Arc::new(builder));
+        // Old builder and its cached instances are dropped outside the lock
+        drop(prev);
+    }
+
+    fn current(&self) -> Arc<dyn TypedBuilder<Impl>> {
+        self.current.read().unwrap().clone()
+    }
+}
+
+impl<Impl> Builder for ReloadableBuilder<Impl>
+where
+    Impl: 'static + Send + Sync,
+{
+    fn instance_type(&self) -> TypeInfo {
+        TypeInfo::of::<Impl>()
+    }
+
+    fn scope_type(&self) -> TypeInfo {
+        self.current().scope_type()
+    }
+
+    fn scope_lifetime(&self) -> TypeInfo {
+        self.current().scope_lifetime()
+    }
+
+    fn interfaces(&self, clb: &mut dyn FnMut(&TypeInfo) -> bool) {
+        self.current().interfaces(clb);
+    }
+
+    fn dependencies(&self, clb: &mut dyn FnMut(&DependencyInfo) -> bool) {
+        self.current().dependencies(clb);
+    }
+
+    fn metadata<'a>(&'a self, _clb: &mut dyn FnMut(&'a dyn Any) -> bool) {}
+
+    fn get_any(
+        &self,
+        cat: &Catalog,
+        ctx: &InjectionContext,
+    ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError> {
+        // Lock is not held during construction
+        self.current().get_any(cat, ctx)
+    }
+
+    fn evict(&self, cat: &Catalog) {
+        self.current().evict(cat);
+    }
+}
+
+impl<Impl> TypedBuilder<Impl> for ReloadableBuilder<Impl>
+where
+    Impl: 'static + Send + Sync,
+{
+    fn get_with_context(
+        &self,
+        cat: &Catalog,
+        ctx: &InjectionContext,
+    ) -> Result<Arc<Impl>, InjectionError> {
+        self.current().get_with_context(cat, ctx)
+    }
+
+    fn bind_interfaces(&self, cat: &mut CatalogBuilder) {
+        self.current().bind_interfaces(cat);
+    }
+}
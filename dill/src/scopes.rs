@@ -1,9 +1,12 @@
 use std::any::Any;
+use std::collections::HashMap;
+use std::hash::Hash;
 use std::marker::PhantomData;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::thread::ThreadId;
 
-use crate::InjectionError;
 use crate::cache::Cache;
+use crate::{InjectionContext, InjectionError, TypeInfo};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -13,10 +16,38 @@ pub trait Scope {
     fn get_or_create<Clb>(
         &self,
         cat: &crate::Catalog,
+        ctx: &InjectionContext,
+        instance_type: TypeInfo,
         create_instance: Clb,
     ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError>
     where
         Clb: FnOnce() -> Result<Arc<dyn Any + Send + Sync>, InjectionError>;
+
+    /// Drops the cached instances, if any, so that the next resolution will
+    /// create a new one. Catalog is used the same way as in
+    /// [`Scope::get_or_create()`] to locate the relevant cache.
+    ///
+    /// Does nothing by default, e.g. [`Singleton`] instances cannot be evicted.
+    fn evict(&self, _cat: &crate::Catalog) {}
+
+    /// The built-in scope that the lifetime of instances is equivalent to,
+    /// used by validation to detect scope inversions. Custom scopes are not
+    /// checked unless they override it.
+    fn lifetime() -> TypeInfo
+    where
+        Self: Sized + 'static,
+    {
+        TypeInfo::of::<Self>()
+    }
+
+    /// Type of the runtime key that instances are cached by, if any.
+    /// Arguments of this type are injected via [`crate::KeyOf`] spec.
+    fn key_type() -> Option<TypeInfo>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -46,6 +77,8 @@ impl Scope for Transient {
     fn get_or_create<Clb>(
         &self,
         _cat: &crate::Catalog,
+        _ctx: &InjectionContext,
+        _instance_type: TypeInfo,
         create_instance: Clb,
     ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError>
     where
@@ -80,6 +113,8 @@ impl Scope for Agnostic {
     fn get_or_create<Clb>(
         &self,
         _cat: &crate::Catalog,
+        _ctx: &InjectionContext,
+        _instance_type: TypeInfo,
         create_instance: Clb,
     ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError>
     where
@@ -95,8 +130,21 @@ impl Scope for Agnostic {
 
 /// Caches an instance upon first creation for the entire duration of the
 /// program.
+///
+/// Once the instance is created, resolving it doesn't take any locks. The
+/// instance is constructed without holding any locks too. Concurrent
+/// resolutions from other threads will wait for the construction to finish,
+/// while an attempt to resolve the same singleton again from within its own
+/// construction (e.g. due to a dependency cycle) will result in
+/// [`InjectionError::Cycle`] error instead of a deadlock.
+///
+/// Instances cannot be evicted via [`crate::Catalog::evict()`] and live for as
+/// long as the builder does.
 pub struct Singleton {
-    instance: Mutex<Option<Arc<dyn Any + Send + Sync>>>,
+    instance: OnceLock<Arc<dyn Any + Send + Sync>>,
+    /// Thread that is currently constructing the instance, if any
+    initializing: Mutex<Option<ThreadId>>,
+    initialized: Condvar,
 }
 
 impl Default for Singleton {
@@ -108,7 +156,9 @@ impl Default for Singleton {
 impl Singleton {
     pub fn new() -> Self {
         Self {
-            instance: Mutex::new(None),
+            instance: OnceLock::new(),
+            initializing: Mutex::new(None),
+            initialized: Condvar::new(),
         }
     }
 }
@@ -117,19 +167,58 @@ impl Scope for Singleton {
     fn get_or_create<Clb>(
         &self,
         _cat: &crate::Catalog,
+        ctx: &InjectionContext,
+        instance_type: TypeInfo,
         create_instance: Clb,
     ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError>
     where
         Clb: FnOnce() -> Result<Arc<dyn Any + Send + Sync>, InjectionError>,
     {
-        let mut cached = self.instance.lock().unwrap();
-        if let Some(inst) = cached.as_ref() {
-            Ok(inst.clone())
-        } else {
-            let inst = create_instance()?;
-            *cached = Some(inst.clone());
-            Ok(inst)
+        // Fast path
+        if let Some(inst) = self.instance.get() {
+            return Ok(inst.clone());
         }
+
+        let this_thread = std::thread::current().id();
+
+        {
+            let mut initializing = self.initializing.lock().unwrap();
+            loop {
+                if let Some(inst) = self.instance.get() {
+                    return Ok(inst.clone());
+                }
+                match *initializing {
+                    Some(thread) if thread == this_thread => {
+                        return Err(InjectionError::cycle(instance_type, ctx));
+                    }
+                    Some(_) => {
+                        initializing = self.initialized.wait(initializing).unwrap();
+                    }
+                    None => {
+                        *initializing = Some(this_thread);
+                        break;
+                    }
+                }
+            }
+        }
+
+        // Resets the state if construction fails or panics, letting waiting
+        // threads to re-attempt it
+        let _guard = SingletonInitGuard(self);
+        let inst = create_instance()?;
+
+        // Only the thread that marked itself as initializing can get here
+        let _ = self.instance.set(inst.clone());
+        Ok(inst)
+    }
+}
+
+struct SingletonInitGuard<'a>(&'a Singleton);
+
+impl Drop for SingletonInitGuard<'_> {
+    fn drop(&mut self) {
+        *self.0.initializing.lock().unwrap() = None;
+        self.0.initialized.notify_all();
     }
 }
 
@@ -161,6 +250,8 @@ impl<T: Cache> Scope for Cached<T> {
     fn get_or_create<Clb>(
         &self,
         cat: &crate::Catalog,
+        ctx: &InjectionContext,
+        instance_type: TypeInfo,
         create_instance: Clb,
     ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError>
     where
@@ -168,13 +259,14 @@ impl<T: Cache> Scope for Cached<T> {
     {
         let id = self as *const Self as usize;
         let cache = cat.get_one::<T>()?;
+        cache.get_or_insert_with(id, instance_type, ctx, create_instance)
+    }
 
-        if let Some(inst) = cache.get(id) {
-            Ok(inst.clone())
-        } else {
-            let inst = create_instance()?;
-            cache.set(id, inst.clone());
-            Ok(inst)
+    /// Removes the instance from the cache present in the catalog, if any
+    fn evict(&self, cat: &crate::Catalog) {
+        let id = self as *const Self as usize;
+        if let Ok(cache) = cat.get_one::<T>() {
+            cache.remove(id);
         }
     }
 }
@@ -240,8 +332,153 @@ impl Cache for TransactionCache {
     }
 
     #[inline(always)]
-    fn set(&self, id: usize, inst: Arc<dyn Any + Send + Sync>) {
-        self.0.set(id, inst)
+    fn set(&self, id: usize, instance_type: TypeInfo, inst: Arc<dyn Any + Send + Sync>) {
+        self.0.set(id, instance_type, inst);
+    }
+
+    #[inline(always)]
+    fn get_or_insert_with<F>(
+        &self,
+        id: usize,
+        instance_type: TypeInfo,
+        ctx: &InjectionContext,
+        create_instance: F,
+    ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError>
+    where
+        F: FnOnce() -> Result<Arc<dyn Any + Send + Sync>, InjectionError>,
+    {
+        self.0
+            .get_or_insert_with(id, instance_type, ctx, create_instance)
+    }
+
+    #[inline(always)]
+    fn remove(&self, id: usize) -> Option<Arc<dyn Any + Send + Sync>> {
+        self.0.remove(id)
+    }
+
+    #[inline(always)]
+    fn clear(&self) {
+        self.0.clear();
+    }
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline(always)]
+    fn entries(&self) -> Vec<crate::cache::CacheEntry> {
+        self.0.entries()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Keyed
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Caches one instance per distinct value of the runtime key `K`. The key is
+/// expected to be present in the catalog the instance is resolved from, which
+/// is most conveniently done via [`crate::Catalog::get_keyed()`].
+///
+/// The key can be injected into the component itself by declaring an argument
+/// or a field of type `K`, which will be resolved via [`crate::KeyOf`] spec.
+///
+/// Instances live for as long as the builder, unless evicted via
+/// [`crate::Catalog::evict_keyed()`].
+///
+/// Example use:
+/// ```
+/// #[derive(Clone, PartialEq, Eq, Hash)]
+/// struct TenantId(String);
+///
+/// #[dill::component]
+/// #[dill::scope(dill::scopes::Keyed<TenantId>)]
+/// struct DbPool {
+///     tenant: TenantId,
+/// }
+///
+/// let cat = dill::Catalog::builder().add::<DbPool>().build();
+///
+/// let a1 = cat.get_keyed::<dill::OneOf<DbPool>>(TenantId("a".into())).unwrap();
+/// let a2 = cat.get_keyed::<dill::OneOf<DbPool>>(TenantId("a".into())).unwrap();
+/// let b = cat.get_keyed::<dill::OneOf<DbPool>>(TenantId("b".into())).unwrap();
+///
+/// assert!(std::sync::Arc::ptr_eq(&a1, &a2));
+/// assert!(!std::sync::Arc::ptr_eq(&a1, &b));
+/// assert_eq!(b.tenant.0, "b");
+/// ```
+pub struct Keyed<K> {
+    /// Every key gets its own [`Singleton`] slot, so that instances of
+    /// different keys are constructed independently
+    instances: Mutex<HashMap<K, Arc<Singleton>>>,
+}
+
+impl<K> Default for Keyed<K> {
+    fn default() -> Self {
+        Self {
+            instances: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<K> Keyed<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<K> Scope for Keyed<K>
+where
+    K: Hash + Eq + Clone + Send + Sync + 'static,
+{
+    fn get_or_create<Clb>(
+        &self,
+        cat: &crate::Catalog,
+        ctx: &InjectionContext,
+        instance_type: TypeInfo,
+        create_instance: Clb,
+    ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError>
+    where
+        Clb: FnOnce() -> Result<Arc<dyn Any + Send + Sync>, InjectionError>,
+    {
+        let key = cat.get_one::<K>()?;
+
+        // The lock is not held during construction, so that instances of other
+        // keys can be resolved meanwhile and re-entrant resolution of the same
+        // key is reported as a cycle by the slot
+        let slot = self
+            .instances
+            .lock()
+            .unwrap()
+            .entry(key.as_ref().clone())
+            .or_default()
+            .clone();
+
+        slot.get_or_create(cat, ctx, instance_type, create_instance)
+    }
+
+    /// Evicts the instance associated with the key present in the catalog, or
+    /// all instances if catalog contains no key. Instances that are being
+    /// constructed during eviction are handed out to their resolvers, but are
+    /// not cached.
+    fn evict(&self, cat: &crate::Catalog) {
+        let mut instances = self.instances.lock().unwrap();
+        match cat.get_one::<K>() {
+            Ok(key) => {
+                instances.remove(key.as_ref());
+            }
+            Err(InjectionError::Unregistered(_)) => instances.clear(),
+            Err(_) => {}
+        }
+    }
+
+    /// Keyed instances are as long-lived as singletons
+    fn lifetime() -> TypeInfo {
+        TypeInfo::of::<Singleton>()
+    }
+
+    fn key_type() -> Option<TypeInfo> {
+        Some(TypeInfo::of::<K>())
     }
 }
 
@@ -1,8 +1,9 @@
+use std::any::TypeId;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
 use crate::injection_context::InjectionContext;
-use crate::{Catalog, InjectionError};
+use crate::{BindingsView, Catalog, IfaceTypeId, InjectionError, TypecastBuilder};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // DependencySpec
@@ -13,10 +14,67 @@ pub trait DependencySpec {
     type IfaceType: ?Sized;
     type ReturnType;
 
+    /// Describes how the [`Self::IfaceType`] is resolved for the purposes of
+    /// [`crate::CatalogBuilder::validate()`]. Custom specs are not validated
+    /// unless they override it.
+    const KIND: SpecKind = SpecKind::UNCHECKED;
+
     /// Resolve and create instances
     fn get(cat: &Catalog, ctx: &InjectionContext) -> Result<Self::ReturnType, InjectionError>;
 }
 
+/// Describes how a [`DependencySpec`] resolves the dependency, letting the
+/// validation check it without knowing the spec type
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SpecKind {
+    /// Whether the dependency is checked by validation at all
+    pub checked: bool,
+    /// Whether all implementations of the interface are resolved instead of
+    /// exactly one
+    pub all: bool,
+    /// Whether the resolution succeeds when the interface is not registered
+    pub optional: bool,
+    /// Whether the ownership of the instance is taken, which requires it to
+    /// not be cached by the scope
+    pub owned: bool,
+    /// Whether only a weak reference to the instance is held, which doesn't
+    /// extend its lifetime
+    pub weak: bool,
+}
+
+impl SpecKind {
+    /// Resolution logic is unknown and is not validated
+    pub const UNCHECKED: Self = Self {
+        checked: false,
+        ..Self::ONE
+    };
+
+    /// Resolves exactly one implementation of the interface
+    pub const ONE: Self = Self {
+        checked: true,
+        all: false,
+        optional: false,
+        owned: false,
+        weak: false,
+    };
+
+    /// Resolves all implementations of the interface, if any
+    pub const ALL: Self = Self {
+        all: true,
+        optional: true,
+        ..Self::ONE
+    };
+
+    /// Same kind, but the resolution succeeds when the interface is not
+    /// registered
+    pub const fn optional(self) -> Self {
+        Self {
+            optional: true,
+            ..self
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // OneOf
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -38,16 +96,21 @@ where
     type IfaceType = Iface;
     type ReturnType = Arc<Iface>;
 
+    const KIND: SpecKind = SpecKind::ONE;
+
     fn get(cat: &Catalog, ctx: &InjectionContext) -> Result<Self::ReturnType, InjectionError> {
-        let mut builders = cat.builders_for::<Iface>();
-        if let Some(first) = builders.next() {
-            if builders.next().is_some() {
-                Err(InjectionError::ambiguous::<Iface>(ctx))
-            } else {
-                first.get_with_context(cat, ctx)
+        let (value, bindings) = cat
+            .0
+            .candidates_for(IfaceTypeId(TypeId::of::<Iface>()), BindingsView::OneOf);
+        match (value, &bindings[..]) {
+            (None, []) => Err(InjectionError::unregistered::<Iface>(ctx)),
+            (Some(value), []) => {
+                TypecastBuilder::<Iface>::from_overlay_value(value).get_with_context(cat, ctx)
+            }
+            (None, [binding]) => {
+                TypecastBuilder::<Iface>::from_binding(binding).get_with_context(cat, ctx)
             }
-        } else {
-            Err(InjectionError::unregistered::<Iface>(ctx))
+            _ => Err(InjectionError::ambiguous::<Iface>(ctx)),
         }
     }
 }
@@ -72,8 +135,16 @@ where
     type IfaceType = Iface;
     type ReturnType = Vec<Arc<Iface>>;
 
+    const KIND: SpecKind = SpecKind::ALL;
+
     fn get(cat: &Catalog, ctx: &InjectionContext) -> Result<Self::ReturnType, InjectionError> {
-        cat.builders_for::<Iface>()
+        let (value, bindings) = cat
+            .0
+            .candidates_for(IfaceTypeId(TypeId::of::<Iface>()), BindingsView::AllOf);
+        value
+            .map(TypecastBuilder::<Iface>::from_overlay_value)
+            .into_iter()
+            .chain(bindings.iter().map(TypecastBuilder::<Iface>::from_binding))
             .map(|b| b.get_with_context(cat, ctx))
             .collect()
     }
@@ -92,6 +163,8 @@ impl<Inner: DependencySpec + 'static> DependencySpec for Maybe<Inner> {
     type IfaceType = Inner::IfaceType;
     type ReturnType = Option<Inner::ReturnType>;
 
+    const KIND: SpecKind = Inner::KIND.optional();
+
     fn get(cat: &Catalog, ctx: &InjectionContext) -> Result<Self::ReturnType, InjectionError> {
         match Inner::get(cat, ctx) {
             Ok(v) => Ok(Some(v)),
@@ -101,6 +174,34 @@ impl<Inner: DependencySpec + 'static> DependencySpec for Maybe<Inner> {
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// KeyOf
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Resolves the runtime key of the [`crate::scopes::Keyed`] scope that the
+/// instance is being created for. The key is expected to be provided via
+/// [`Catalog::get_keyed()`].
+pub struct KeyOf<K>
+where
+    K: 'static + Send + Sync,
+{
+    _dummy: PhantomData<K>,
+}
+
+impl<K> DependencySpec for KeyOf<K>
+where
+    K: 'static + Send + Sync,
+{
+    type IfaceType = K;
+    type ReturnType = Arc<K>;
+
+    const KIND: SpecKind = SpecKind::ONE.optional();
+
+    fn get(cat: &Catalog, ctx: &InjectionContext) -> Result<Self::ReturnType, InjectionError> {
+        OneOf::<K>::get(cat, ctx)
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Lazy
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -116,6 +217,8 @@ impl<Inner: DependencySpec + 'static> DependencySpec for Lazy<Inner> {
     type IfaceType = Inner::IfaceType;
     type ReturnType = crate::lazy::Lazy<Inner::ReturnType>;
 
+    const KIND: SpecKind = Inner::KIND;
+
     #[cfg(not(feature = "tokio"))]
     fn get(cat: &Catalog, _ctx: &InjectionContext) -> Result<Self::ReturnType, InjectionError> {
         let cat = cat.clone();
@@ -136,3 +239,175 @@ impl<Inner: DependencySpec + 'static> DependencySpec for Lazy<Inner> {
         ))
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Reloadable
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Returns a handle that resolves the current instance upon every call,
+/// picking up the builders replaced via [`Catalog::reload()`].
+///
+/// See [`crate::reloadable::Reloadable`] documentation for details.
+pub struct Reloadable<Inner: DependencySpec + 'static> {
+    _dummy: PhantomData<Inner>,
+}
+
+impl<Inner: DependencySpec + 'static> DependencySpec for Reloadable<Inner> {
+    type IfaceType = Inner::IfaceType;
+    type ReturnType = crate::reloadable::Reloadable<Inner::ReturnType>;
+
+    const KIND: SpecKind = Inner::KIND;
+
+    fn get(cat: &Catalog, _ctx: &InjectionContext) -> Result<Self::ReturnType, InjectionError> {
+        Ok(crate::reloadable::Reloadable::new(cat.weak_ref(), |cat| {
+            cat.get::<Inner>()
+        }))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Owned
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Builds a fresh instance of a type and hands over its ownership instead of
+/// returning an [`Arc`], e.g. for components that need `&mut self`.
+///
+/// Only works with builders that don't retain the instances, like those in
+/// [`crate::Transient`] and [`crate::Agnostic`] scopes. Resolving an instance
+/// that is shared by a caching scope will result in
+/// [`InjectionError::Ownership`] error.
+pub struct Owned<Impl>
+where
+    Impl: 'static + Send + Sync,
+{
+    _dummy: PhantomData<Impl>,
+}
+
+impl<Impl> DependencySpec for Owned<Impl>
+where
+    Impl: 'static + Send + Sync,
+{
+    type IfaceType = Impl;
+    type ReturnType = Impl;
+
+    const KIND: SpecKind = SpecKind {
+        owned: true,
+        ..SpecKind::ONE
+    };
+
+    fn get(cat: &Catalog, ctx: &InjectionContext) -> Result<Self::ReturnType, InjectionError> {
+        let inst = OneOf::<Impl>::get(cat, ctx)?;
+        Arc::try_unwrap(inst).map_err(|_| InjectionError::ownership::<Impl>(ctx))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// OwnedBox
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Same as [`Owned`], but returns the instance in a [`Box`], which allows
+/// taking the ownership of a trait object, e.g. `Box<dyn Iface>`.
+pub struct OwnedBox<Iface>
+where
+    Iface: 'static + ?Sized + Send + Sync,
+{
+    _dummy: PhantomData<Iface>,
+}
+
+impl<Iface> DependencySpec for OwnedBox<Iface>
+where
+    Iface: 'static + ?Sized + Send + Sync,
+{
+    type IfaceType = Iface;
+    type ReturnType = Box<Iface>;
+
+    const KIND: SpecKind = SpecKind {
+        owned: true,
+        ..SpecKind::ONE
+    };
+
+    fn get(cat: &Catalog, ctx: &InjectionContext) -> Result<Self::ReturnType, InjectionError> {
+        let (value, bindings) = cat
+            .0
+            .candidates_for(IfaceTypeId(TypeId::of::<Iface>()), BindingsView::OneOf);
+        match (value, &bindings[..]) {
+            (None, []) => Err(InjectionError::unregistered::<Iface>(ctx)),
+            (Some(value), []) => {
+                TypecastBuilder::<Iface>::from_overlay_value(value).get_owned_with_context(cat, ctx)
+            }
+            (None, [binding]) => {
+                TypecastBuilder::<Iface>::from_binding(binding).get_owned_with_context(cat, ctx)
+            }
+            _ => Err(InjectionError::ambiguous::<Iface>(ctx)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WeakOf
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Returns a [`std::sync::Weak`] reference to a single instance of type
+/// implementing specific interface, e.g. to let a child singleton refer back to
+/// its parent without creating an ownership cycle.
+///
+/// If the requested type is currently being constructed (i.e. the parent
+/// depends on the component that requests the reference) the reference to the
+/// instance under construction is returned. Such a reference cannot be upgraded
+/// until the construction is finished. See [`crate::cyclic::construct()`].
+/// Cycles are resolved only when the strong side is resolved first - resolving
+/// the component that holds the weak reference first will construct the other
+/// side, which in turn will construct a new instance of the former.
+///
+/// Otherwise the reference is downgraded from the resolved instance, so when
+/// it is not cached by its scope (e.g. [`crate::Transient`] or
+/// [`crate::Agnostic`]) the reference is already dangling upon injection.
+/// Validation reports such dependencies as scope inversions.
+pub struct WeakOf<Iface>
+where
+    Iface: 'static + ?Sized + Send + Sync,
+{
+    _dummy: PhantomData<Iface>,
+}
+
+impl<Iface> DependencySpec for WeakOf<Iface>
+where
+    Iface: 'static + ?Sized + Send + Sync,
+{
+    type IfaceType = Iface;
+    type ReturnType = std::sync::Weak<Iface>;
+
+    const KIND: SpecKind = SpecKind {
+        weak: true,
+        ..SpecKind::ONE
+    };
+
+    fn get(cat: &Catalog, ctx: &InjectionContext) -> Result<Self::ReturnType, InjectionError> {
+        if let Some(weak) = crate::cyclic::weak_under_construction::<Iface>(cat) {
+            return Ok(weak);
+        }
+        let inst = OneOf::<Iface>::get(cat, ctx)?;
+        Ok(Arc::downgrade(&inst))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Fallible
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Defers the errors of resolving the inner spec to the component, returning
+/// a [`Result`] instead of failing the injection.
+pub struct Fallible<Inner: DependencySpec + 'static> {
+    _dummy: PhantomData<Inner>,
+}
+
+impl<Inner: DependencySpec + 'static> DependencySpec for Fallible<Inner> {
+    type IfaceType = Inner::IfaceType;
+    type ReturnType = Result<Inner::ReturnType, InjectionError>;
+
+    const KIND: SpecKind = Inner::KIND.optional();
+
+    fn get(cat: &Catalog, ctx: &InjectionContext) -> Result<Self::ReturnType, InjectionError> {
+        Ok(Inner::get(cat, ctx))
+    }
+}
@@ -1,6 +1,6 @@
 use std::any::Any;
-use std::marker::PhantomData;
-use std::sync::Arc;
+use std::marker::{PhantomData, Unsize};
+use std::sync::{Arc, Weak};
 
 use crate::injection_context::InjectionContext;
 use crate::*;
@@ -9,14 +9,19 @@ use crate::*;
 
 #[derive(Clone)]
 pub(crate) struct Binding {
-    pub caster: Arc<AnyTypeCaster>,
+    pub caster: &'static AnyTypeCaster,
     pub builder: Arc<dyn Builder>,
 }
 
 impl Binding {
-    pub(crate) fn new(caster: Arc<AnyTypeCaster>, builder: Arc<dyn Builder>) -> Self {
+    pub(crate) fn new(caster: &'static AnyTypeCaster, builder: Arc<dyn Builder>) -> Self {
         Self { caster, builder }
     }
+
+    /// Whether both bindings refer to the same builder
+    pub(crate) fn is_same(&self, other: &Binding) -> bool {
+        Arc::ptr_eq(&self.builder, &other.builder)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -42,6 +47,10 @@ where
         self.builder.scope_type()
     }
 
+    fn scope_lifetime(&self) -> TypeInfo {
+        self.builder.scope_lifetime()
+    }
+
     fn interfaces(&self, clb: &mut dyn FnMut(&TypeInfo) -> bool) {
         self.builder.interfaces(clb);
     }
@@ -61,6 +70,10 @@ where
     ) -> Result<Arc<dyn Any + Send + Sync>, InjectionError> {
         self.builder.get_any(cat, ctx)
     }
+
+    fn evict(&self, cat: &Catalog) {
+        self.builder.evict(cat);
+    }
 }
 
 impl<'a, Iface> TypecastBuilder<'a, Iface>
@@ -71,6 +84,20 @@ where
         Self { builder, caster }
     }
 
+    pub(crate) fn from_binding(binding: &'a Binding) -> Self {
+        // SAFETY: the TypeID key of the `bindings` map is guaranteed to match the
+        // `Iface` type
+        let caster: &TypeCaster<Iface> = binding.caster.downcast_ref().unwrap();
+        Self::new(binding.builder.as_ref(), caster)
+    }
+
+    pub(crate) fn from_overlay_value(value: &'a OverlayValue) -> Self {
+        // SAFETY: overlay values are only matched by their own type which is
+        // guaranteed to match the `Iface` type
+        let caster: &TypeCaster<Iface> = value.caster.downcast_ref().unwrap();
+        Self::new(value, caster)
+    }
+
     pub fn get(&self, cat: &Catalog) -> Result<Arc<Iface>, InjectionError> {
         self.get_with_context(cat, &InjectionContext::new_root())
     }
@@ -83,12 +110,88 @@ where
         let inst = self.builder.get_any(cat, ctx)?;
         Ok((self.caster.cast_arc)(inst))
     }
+
+    /// Same as [`Self::get_with_context()`], but takes the ownership of the
+    /// instance, failing with [`InjectionError::Ownership`] if it's shared
+    pub fn get_owned_with_context(
+        &self,
+        cat: &Catalog,
+        ctx: &InjectionContext,
+    ) -> Result<Box<Iface>, InjectionError> {
+        let inst = self.builder.get_any(cat, ctx)?;
+        (self.caster.cast_box)(inst).ok_or_else(|| InjectionError::ownership::<Iface>(ctx))
+    }
+
+    /// Casts a type-erased `Weak` reference to an instance of this builder,
+    /// returns `None` if the reference is to an instance of a different type
+    pub(crate) fn cast_weak(&self, weak: &dyn Any) -> Option<Weak<Iface>> {
+        (self.caster.cast_weak)(weak)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub(crate) struct TypeCaster<Into: ?Sized> {
     pub cast_arc: fn(Arc<dyn Any + Send + Sync>) -> Arc<Into>,
+    /// Takes the ownership of the instance, returns `None` if it's shared
+    pub cast_box: fn(Arc<dyn Any + Send + Sync>) -> Option<Box<Into>>,
+    /// Casts `&Weak<Impl>`, returns `None` if the reference is of another type
+    pub cast_weak: fn(&dyn Any) -> Option<Weak<Into>>,
+}
+
+impl<Impl> TypeCaster<Impl>
+where
+    Impl: 'static + Send + Sync,
+{
+    /// Returns a caster of `Impl` instances into themselves
+    pub(crate) fn of_impl() -> &'static AnyTypeCaster {
+        &const {
+            TypeCaster::<Impl> {
+                // SAFETY: `TypeCaster<Impl>` is guaranteed to be invoked only on the `Impl`
+                // instances
+                cast_arc: |v| v.downcast().unwrap(),
+                cast_box: |v| {
+                    let s: Arc<Impl> = v.downcast().unwrap();
+                    Arc::try_unwrap(s).ok().map(Box::new)
+                },
+                cast_weak: |v| v.downcast_ref::<Weak<Impl>>().cloned(),
+            }
+        }
+    }
+}
+
+impl<Iface> TypeCaster<Iface>
+where
+    Iface: 'static + ?Sized,
+{
+    /// Returns a caster of `Impl` instances into `Iface` trait objects
+    pub(crate) fn of_iface<Impl>() -> &'static AnyTypeCaster
+    where
+        Impl: 'static + Send + Sync + Unsize<Iface>,
+    {
+        &const {
+            TypeCaster::<Iface> {
+                cast_arc: |v| {
+                    // SAFETY: `TypeCaster<Iface>` is guaranteed to be invoked only on the
+                    // `Impl` instances
+                    let s: Arc<Impl> = v.downcast().unwrap();
+                    let t: Arc<Iface> = s;
+                    t
+                },
+                cast_box: |v| {
+                    let s: Arc<Impl> = v.downcast().unwrap();
+                    let s: Box<Impl> = Box::new(Arc::try_unwrap(s).ok()?);
+                    let t: Box<Iface> = s;
+                    Some(t)
+                },
+                cast_weak: |v| {
+                    let s: Weak<Impl> = v.downcast_ref::<Weak<Impl>>()?.clone();
+                    let t: Weak<Iface> = s;
+                    Some(t)
+                },
+            }
+        }
+    }
 }
 
 pub(crate) type AnyTypeCaster = dyn Any + Send + Sync;
@@ -119,11 +222,7 @@ impl<'a, Iface: 'static + ?Sized> Iterator for TypecastBuilderIterator<'a, Iface
         {
             let b = &bindings[self.pos];
             self.pos += 1;
-
-            // SAFETY: the TypeID key of the `bindings` map is guaranteed to match the
-            // `Iface` type
-            let caster: &TypeCaster<Iface> = b.caster.downcast_ref().unwrap();
-            return Some(TypecastBuilder::new(b.builder.as_ref(), caster));
+            return Some(TypecastBuilder::from_binding(b));
         }
         None
     }
@@ -168,10 +267,7 @@ where
                 self.pos += 1;
 
                 if (self.pred)(b.builder.as_ref()) {
-                    // SAFETY: the TypeID key of the `bindings` map is guaranteed to match the
-                    // `Iface` type
-                    let caster: &TypeCaster<Iface> = b.caster.downcast_ref().unwrap();
-                    return Some(TypecastBuilder::new(b.builder.as_ref(), caster));
+                    return Some(TypecastBuilder::from_binding(b));
                 }
             }
         }
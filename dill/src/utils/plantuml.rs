@@ -53,6 +53,10 @@ fn get_spec_name(i: &DependencyInfo) -> String {
         "AllOf<>" => "*".to_string(),
         "Maybe<OneOf<>>" => "?".to_string(),
         "Lazy<OneOf<>>" => "lazy".to_string(),
+        "Reloadable<OneOf<>>" => "reloadable".to_string(),
+        "Owned<>" | "OwnedBox<>" => "owned".to_string(),
+        "WeakOf<>" => "weak".to_string(),
+        "KeyOf<>" => "key".to_string(),
         _ => spec,
     };
 
@@ -0,0 +1,97 @@
+//! Measures heap allocations of creating per-request catalogs. Lives in its
+//! own test binary as it installs a counting global allocator.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::Arc;
+
+use dill::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|c| c.set(c.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[component]
+struct Subject {
+    name: Arc<String>,
+}
+
+#[component]
+#[scope(Transaction)]
+struct Service {
+    subject: Arc<Subject>,
+}
+
+#[test]
+fn test_overlay_catalog_allocations() {
+    let base = CatalogBuilder::new()
+        .add::<Subject>()
+        .add::<Service>()
+        .build();
+
+    let new_request_catalog_chained = || {
+        let cat = base
+            .builder_chained()
+            .add_value(String::from("bob"))
+            .add_value(TransactionCache::new())
+            .build();
+        let service = cat.get_one::<Service>().unwrap();
+        assert_eq!(service.subject.name.as_str(), "bob");
+    };
+
+    let new_request_catalog_overlay = || {
+        let cat = base
+            .builder_overlay()
+            .add_value(String::from("bob"))
+            .add_value(TransactionCache::new())
+            .build();
+        let service = cat.get_one::<Service>().unwrap();
+        assert_eq!(service.subject.name.as_str(), "bob");
+    };
+
+    // Warm up the resolution index of the base catalog
+    new_request_catalog_chained();
+    new_request_catalog_overlay();
+
+    // Values, the overlay catalog itself, the cached instances and the
+    // transaction cache state - but nothing proportional to the number of
+    // bindings in the base catalog
+    const MAX_ALLOCATIONS: usize = 10;
+
+    let chained = count_allocations(new_request_catalog_chained);
+    let overlay = count_allocations(new_request_catalog_overlay);
+    assert!(
+        overlay * 2 <= chained,
+        "chained={chained} overlay={overlay}"
+    );
+    assert!(
+        overlay <= MAX_ALLOCATIONS,
+        "overlay={overlay} max={MAX_ALLOCATIONS}"
+    );
+}
@@ -4,6 +4,7 @@ mod test_graphviz;
 mod test_macros;
 mod test_metadata;
 mod test_plantuml;
+mod test_reloadable;
 mod test_scopes;
 mod test_specs;
 mod test_threads;
@@ -1,3 +1,6 @@
+use std::assert_matches::assert_matches;
+use std::fmt::Display;
+use std::marker::PhantomData;
 use std::sync::Arc;
 
 use dill::*;
@@ -59,6 +62,156 @@ fn test_default_interfaces() {
     assert_eq!(a.test(), "foo");
 }
 
+#[test]
+fn test_default_interfaces_supertraits() {
+    trait Reader: Send + Sync {
+        fn read(&self) -> String;
+    }
+
+    trait Writer: Send + Sync {
+        fn write(&self) -> String;
+    }
+
+    trait Repo: Reader + Writer {}
+
+    #[component]
+    #[scope(Singleton)]
+    #[interface(dyn Repo, supertraits(dyn Reader, dyn Writer))]
+    struct RepoImpl;
+
+    impl Reader for RepoImpl {
+        fn read(&self) -> String {
+            "read".to_owned()
+        }
+    }
+
+    impl Writer for RepoImpl {
+        fn write(&self) -> String {
+            "write".to_owned()
+        }
+    }
+
+    impl Repo for RepoImpl {}
+
+    let cat = CatalogBuilder::new().add::<RepoImpl>().build();
+
+    let b = cat.builders().next().unwrap();
+    assert_eq!(
+        b.interfaces_get_all()
+            .into_iter()
+            .map(|i| i.name)
+            .collect::<Vec<_>>(),
+        vec![
+            "dyn unit::tests::test_builder::test_default_interfaces_supertraits::Repo",
+            "dyn unit::tests::test_builder::test_default_interfaces_supertraits::Reader",
+            "dyn unit::tests::test_builder::test_default_interfaces_supertraits::Writer",
+        ]
+    );
+
+    let repo = cat.get_one::<dyn Repo>().unwrap();
+    let reader = cat.get_one::<dyn Reader>().unwrap();
+    let writer = cat.get_one::<dyn Writer>().unwrap();
+    assert_eq!(reader.read(), "read");
+    assert_eq!(writer.write(), "write");
+
+    // Same cached instance is shared by all interfaces
+    assert!(std::ptr::addr_eq(Arc::as_ptr(&repo), Arc::as_ptr(&reader)));
+    assert!(std::ptr::addr_eq(Arc::as_ptr(&repo), Arc::as_ptr(&writer)));
+}
+
+#[test]
+fn test_default_interfaces_implements() {
+    trait Greeter: Send + Sync {
+        fn greet(&self) -> String;
+    }
+
+    trait Named: Send + Sync {
+        fn name(&self) -> String;
+    }
+
+    trait Other: Send + Sync {}
+
+    #[component]
+    #[interface(dyn Other)]
+    struct GreeterImpl;
+
+    #[implements]
+    impl Greeter for GreeterImpl {
+        fn greet(&self) -> String {
+            format!("hello from {}", self.name())
+        }
+    }
+
+    #[dill::implements]
+    impl Named for GreeterImpl {
+        fn name(&self) -> String {
+            "greeter".to_owned()
+        }
+    }
+
+    // Also listed in `#[interface]` - bound only once
+    #[implements]
+    impl Other for GreeterImpl {}
+
+    // Implementations are matched by type, so aliases are supported
+    trait Aliased: Send + Sync {}
+
+    type GreeterAlias = GreeterImpl;
+
+    #[implements]
+    impl Aliased for GreeterAlias {}
+
+    let cat = CatalogBuilder::new().add::<GreeterImpl>().build();
+
+    let b = cat.builders().next().unwrap();
+    assert_eq!(
+        b.interfaces_get_all()
+            .into_iter()
+            .map(|i| i.name)
+            .collect::<Vec<_>>(),
+        vec![
+            "dyn unit::tests::test_builder::test_default_interfaces_implements::Other",
+            "dyn unit::tests::test_builder::test_default_interfaces_implements::Aliased",
+            "dyn unit::tests::test_builder::test_default_interfaces_implements::Greeter",
+            "dyn unit::tests::test_builder::test_default_interfaces_implements::Named",
+        ]
+    );
+
+    assert_eq!(
+        cat.get_one::<dyn Greeter>().unwrap().greet(),
+        "hello from greeter"
+    );
+    assert_eq!(cat.get_one::<dyn Named>().unwrap().name(), "greeter");
+
+    assert!(cat.get_one::<dyn Other>().is_ok());
+    assert!(cat.get_one::<dyn Aliased>().is_ok());
+
+    // Builder can be cast to the declared interfaces
+    let builder = TypedBuilderCast::<dyn Greeter>::cast(GreeterImpl::builder());
+    assert_eq!(
+        builder
+            .get_with_context(&cat, &InjectionContext::new_root())
+            .unwrap()
+            .greet(),
+        "hello from greeter"
+    );
+    let builder = TypedBuilderCast::<dyn Other>::cast(GreeterImpl::builder());
+    assert!(
+        builder
+            .get_with_context(&cat, &InjectionContext::new_root())
+            .is_ok()
+    );
+
+    // Declared interfaces are not bound when default interfaces are disabled
+    let cat = CatalogBuilder::new()
+        .add_builder(GreeterImpl::builder().without_default_interfaces())
+        .build();
+    assert_matches!(
+        cat.get_one::<dyn Greeter>().err(),
+        Some(InjectionError::Unregistered(_))
+    );
+}
+
 #[test]
 fn test_with_args_by_value() {
     trait A: Send + Sync {
@@ -277,20 +430,437 @@ fn test_new_ctor_by_ref() {
     assert_eq!(inst.test(), "aimpl::foo");
 }
 
-/*#[test]
+#[test]
+fn test_method_injection() {
+    trait Metrics: Send + Sync {
+        fn name(&self) -> String;
+    }
+
+    #[component]
+    #[interface(dyn Metrics)]
+    struct MetricsImpl;
+    impl Metrics for MetricsImpl {
+        fn name(&self) -> String {
+            "metrics".to_owned()
+        }
+    }
+
+    struct AImpl {
+        prefix: String,
+        metrics: Option<Arc<dyn Metrics>>,
+        suffix: String,
+        calls: usize,
+    }
+
+    #[component]
+    #[scope(Singleton)]
+    impl AImpl {
+        pub fn new(prefix: String) -> Self {
+            Self {
+                prefix,
+                metrics: None,
+                suffix: String::new(),
+                calls: 0,
+            }
+        }
+
+        #[dill::inject]
+        fn set_metrics(&mut self, metrics: Arc<dyn Metrics>) {
+            self.metrics = Some(metrics);
+            self.calls += 1;
+        }
+
+        #[inject]
+        fn set_suffix(&mut self, suffix: &i32) {
+            self.suffix = suffix.to_string();
+        }
+
+        fn test(&self) -> String {
+            format!(
+                "{}::{}::{}",
+                self.prefix,
+                self.metrics.as_ref().unwrap().name(),
+                self.suffix
+            )
+        }
+    }
+
+    let cat = CatalogBuilder::new()
+        .add::<AImpl>()
+        .add::<MetricsImpl>()
+        .add_value("a".to_owned())
+        .add_value(42i32)
+        .build();
+
+    let inst = cat.get_one::<AImpl>().unwrap();
+    assert_eq!(inst.test(), "a::metrics::42");
+
+    // Methods are called before the instance is cached
+    let inst = cat.get_one::<AImpl>().unwrap();
+    assert_eq!(inst.calls, 1);
+
+    assert_eq!(
+        cat.builders_for::<AImpl>()
+            .next()
+            .unwrap()
+            .dependencies_get_all(),
+        [
+            DependencyInfo::of::<String, OneOf<String>>(),
+            DependencyInfo::of::<dyn Metrics, OneOf<dyn Metrics>>(),
+            DependencyInfo::of::<i32, OneOf<i32>>(),
+        ]
+    );
+}
+
+#[test]
+fn test_assisted_injection() {
+    trait Repo: Send + Sync {
+        fn name(&self) -> String;
+    }
+
+    #[component]
+    #[interface(dyn Repo)]
+    struct RepoImpl;
+    impl Repo for RepoImpl {
+        fn name(&self) -> String {
+            "repo".to_owned()
+        }
+    }
+
+    #[component]
+    struct ExportJob {
+        repo: Arc<dyn Repo>,
+        #[component(assisted)]
+        job_id: u64,
+    }
+
+    impl ExportJob {
+        fn run(&self) -> String {
+            format!("{}::{}", self.repo.name(), self.job_id)
+        }
+    }
+
+    #[component]
+    #[scope(Singleton)]
+    struct Exporter {
+        jobs: Arc<Factory<ExportJob>>,
+    }
+
+    let mut b = CatalogBuilder::new();
+    b.add::<ExportJob>().add::<Exporter>();
+
+    // Dependencies of the created component are validated
+    assert_matches!(
+        b.validate(),
+        Err(ValidationError { errors }) if matches!(
+            &errors[..],
+            [InjectionError::Unregistered(u)]
+            if u.dep_type == TypeInfo::of::<dyn Repo>()
+        )
+    );
+
+    b.add::<RepoImpl>();
+    b.validate().unwrap();
+
+    let cat = b.build();
+    let exporter = cat.get_one::<Exporter>().unwrap();
+    assert_eq!(exporter.jobs.create(1).unwrap().run(), "repo::1");
+    assert_eq!(exporter.jobs.create(2).unwrap().run(), "repo::2");
+
+    assert_eq!(
+        cat.builders_for::<Factory<ExportJob>>()
+            .next()
+            .unwrap()
+            .dependencies_get_all(),
+        [DependencyInfo::of::<dyn Repo, OneOf<dyn Repo>>()]
+    );
+}
+
+#[test]
+fn test_provider() {
+    // Types that can't be annotated with #[component], e.g. from other crates
+    struct Config {
+        url: String,
+    }
+
+    struct Client {
+        url: String,
+        timeout: u64,
+    }
+
+    trait HttpClient: Send + Sync {
+        fn url(&self) -> String;
+    }
+    impl HttpClient for Client {
+        fn url(&self) -> String {
+            format!("{} ({}s)", self.url, self.timeout)
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Desc {
+        name: &'static str,
+    }
+
+    #[dill::provider]
+    #[dill::scope(Singleton)]
+    #[dill::interface(dyn HttpClient)]
+    #[dill::meta(Desc { name: "http" })]
+    fn http_client(config: Arc<Config>, #[component(explicit)] timeout: u64) -> Client {
+        Client {
+            url: config.url.clone(),
+            timeout,
+        }
+    }
+
+    let mut b = CatalogBuilder::new();
+    b.add_builder(HttpClientProvider::builder(30));
+
+    assert_matches!(
+        b.validate(),
+        Err(ValidationError { errors }) if matches!(
+            &errors[..],
+            [InjectionError::Unregistered(u)]
+            if u.dep_type == TypeInfo::of::<Config>()
+        )
+    );
+
+    b.add_value(Config {
+        url: "http://foo".to_owned(),
+    });
+    b.validate().unwrap();
+
+    let cat = b.build();
+
+    let client = cat.get_one::<dyn HttpClient>().unwrap();
+    assert_eq!(client.url(), "http://foo (30s)");
+    assert!(Arc::ptr_eq(
+        &cat.get_one::<Client>().unwrap(),
+        &cat.get_one::<Client>().unwrap()
+    ));
+
+    let builder = cat.builders_for::<dyn HttpClient>().next().unwrap();
+    assert_eq!(builder.instance_type(), TypeInfo::of::<Client>());
+    assert_eq!(builder.scope_type(), TypeInfo::of::<Singleton>());
+    assert_eq!(
+        builder.dependencies_get_all(),
+        [DependencyInfo::of::<Config, OneOf<Config>>()]
+    );
+    assert_eq!(
+        builder.metadata_get_first::<Desc>(),
+        Some(&Desc { name: "http" })
+    );
+
+    // Providers without explicit arguments are components
+    #[dill::provider]
+    fn greeting(config: Arc<Config>) -> String {
+        format!("hello {}", config.url)
+    }
+
+    let cat = CatalogBuilder::new_chained(&cat)
+        .add::<GreetingProvider>()
+        .build();
+    assert_eq!(*cat.get_one::<String>().unwrap(), "hello http://foo");
+}
+
+#[test]
+fn test_inject_attributes() {
+    trait Plugin: Send + Sync {
+        fn name(&self) -> &'static str;
+    }
+
+    #[component]
+    #[interface(dyn Plugin)]
+    struct PluginA;
+    impl Plugin for PluginA {
+        fn name(&self) -> &'static str {
+            "a"
+        }
+    }
+
+    #[component]
+    #[interface(dyn Plugin)]
+    struct PluginB;
+    impl Plugin for PluginB {
+        fn name(&self) -> &'static str {
+            "b"
+        }
+    }
+
+    // Custom spec
+    struct PluginNames;
+    impl DependencySpec for PluginNames {
+        type IfaceType = dyn Plugin;
+        type ReturnType = Vec<&'static str>;
+
+        fn get(
+            cat: &dill::Catalog,
+            ctx: &InjectionContext,
+        ) -> Result<Self::ReturnType, InjectionError> {
+            let mut names: Vec<_> = cat
+                .get_with_context::<AllOf<dyn Plugin>>(ctx)?
+                .iter()
+                .map(|p| p.name())
+                .collect();
+            names.sort();
+            Ok(names)
+        }
+    }
+
+    // User type that would otherwise be confused with `dill::Catalog`
+    #[derive(Clone)]
+    struct Catalog {
+        name: String,
+    }
+
+    #[component]
+    struct Host {
+        #[inject(spec = AllOf<dyn Plugin>)]
+        plugins: Vec<Arc<dyn Plugin>>,
+        #[dill::inject(spec = PluginNames)]
+        plugin_names: Vec<&'static str>,
+        #[inject(value)]
+        tags: Vec<String>,
+        #[inject(value)]
+        catalog: Catalog,
+        #[inject(skip, default = 30)]
+        timeout: u64,
+        #[inject(skip)]
+        retries: Option<u32>,
+        #[inject(catalog)]
+        dill_catalog: dill::Catalog,
+    }
+
+    let mut b = CatalogBuilder::new();
+    b.add::<PluginA>()
+        .add::<PluginB>()
+        .add::<Host>()
+        .add_value(vec!["x".to_owned()])
+        .add_value(Catalog {
+            name: "products".to_owned(),
+        });
+    b.validate().unwrap();
+
+    let cat = b.build();
+
+    let host = cat.get_one::<Host>().unwrap();
+    assert_eq!(host.plugins.len(), 2);
+    assert_eq!(host.plugin_names, ["a", "b"]);
+    assert_eq!(host.tags, ["x"]);
+    assert_eq!(host.catalog.name, "products");
+    assert_eq!(host.timeout, 30);
+    assert_eq!(host.retries, None);
+    assert_eq!(host.dill_catalog.get_one::<Vec<String>>().unwrap()[0], "x");
+
+    assert_eq!(
+        cat.builders_for::<Host>()
+            .next()
+            .unwrap()
+            .dependencies_get_all(),
+        [
+            DependencyInfo::of::<dyn Plugin, AllOf<dyn Plugin>>(),
+            DependencyInfo::of::<dyn Plugin, PluginNames>(),
+            DependencyInfo::of::<Vec<String>, OneOf<Vec<String>>>(),
+            DependencyInfo::of::<Catalog, OneOf<Catalog>>(),
+            DependencyInfo::of::<dill::Catalog, OneOf<dill::Catalog>>(),
+        ]
+    );
+
+    // Overrides are available for injected arguments
+    let cat = CatalogBuilder::new()
+        .add_builder(HostBuilder::new().with_plugins_fn(|_| Ok(Vec::new())))
+        .add_value(vec!["x".to_owned()])
+        .add_value(Catalog {
+            name: "products".to_owned(),
+        })
+        .build();
+
+    let host = cat.get_one::<Host>().unwrap();
+    assert!(host.plugins.is_empty());
+    assert!(host.plugin_names.is_empty());
+}
+
+#[test]
+fn test_default_values() {
+    #[derive(Clone, Debug, PartialEq)]
+    struct Timeout(u64);
+
+    fn default_name() -> String {
+        "anonymous".to_owned()
+    }
+
+    #[component]
+    struct Config {
+        #[dill::default(Timeout(30))]
+        timeout: Timeout,
+        #[dill::default_with(default_name)]
+        name: String,
+        #[inject(value)]
+        #[default(vec![1])]
+        ports: Vec<u16>,
+    }
+
+    struct Client {
+        timeout: Timeout,
+    }
+
+    #[component]
+    impl Client {
+        fn new(#[dill::default(Timeout(5))] timeout: Timeout) -> Self {
+            Self { timeout }
+        }
+    }
+
+    let mut b = CatalogBuilder::new();
+    b.add::<Config>().add::<Client>();
+    b.validate().unwrap();
+
+    let cat = b.build();
+    let config = cat.get_one::<Config>().unwrap();
+    assert_eq!(config.timeout, Timeout(30));
+    assert_eq!(config.name, "anonymous");
+    assert_eq!(config.ports, [1]);
+    assert_eq!(cat.get_one::<Client>().unwrap().timeout, Timeout(5));
+
+    assert_eq!(
+        cat.builders_for::<Config>()
+            .next()
+            .unwrap()
+            .dependencies_get_all(),
+        [
+            DependencyInfo::of::<Timeout, Maybe<OneOf<Timeout>>>(),
+            DependencyInfo::of::<String, Maybe<OneOf<String>>>(),
+            DependencyInfo::of::<Vec<u16>, Maybe<OneOf<Vec<u16>>>>(),
+        ]
+    );
+
+    // Registered values take precedence
+    let cat = CatalogBuilder::new_chained(&cat)
+        .add_value(Timeout(60))
+        .add_value("foo".to_owned())
+        .add_value(vec![8080_u16])
+        .build();
+    let config = cat.get_one::<Config>().unwrap();
+    assert_eq!(config.timeout, Timeout(60));
+    assert_eq!(config.name, "foo");
+    assert_eq!(config.ports, [8080]);
+    assert_eq!(cat.get_one::<Client>().unwrap().timeout, Timeout(60));
+}
+
+#[test]
 fn test_generic_type_from_struct() {
     trait A: Send + Sync {
         fn test(&self) -> String;
     }
 
     #[component]
-    struct AImpl<T> {
+    struct AImpl<T: Clone + Send + Sync + 'static> {
         t: T,
     }
 
     impl<T> A for AImpl<T>
     where
-        T: Send + Sync,
+        T: Clone + Send + Sync + 'static,
         T: Display,
     {
         fn test(&self) -> String {
@@ -298,6 +868,7 @@ fn test_generic_type_from_struct() {
         }
     }
 
+    #[derive(Clone)]
     struct B(String);
 
     impl Display for B {
@@ -314,9 +885,9 @@ fn test_generic_type_from_struct() {
 
     let inst = cat.get::<OneOf<dyn A>>().unwrap();
     assert_eq!(inst.test(), "aimpl::foo");
-}*/
+}
 
-/*#[test]
+#[test]
 fn test_generic_type_from_impl() {
     trait A: Send + Sync {
         fn test(&self) -> String;
@@ -324,7 +895,7 @@ fn test_generic_type_from_impl() {
 
     struct AImpl<T> {
         b: String,
-        _p: PhantomData<T>,
+        _p: PhantomData<fn() -> T>,
     }
 
     #[component]
@@ -353,4 +924,68 @@ fn test_generic_type_from_impl() {
 
     let inst = cat.get::<OneOf<dyn A>>().unwrap();
     assert_eq!(inst.test(), "aimpl::foo::u8");
-}*/
+}
+
+#[test]
+fn test_generic_family() {
+    trait Entity: Send + Sync + 'static {
+        const TABLE: &'static str;
+    }
+
+    struct User;
+    impl Entity for User {
+        const TABLE: &'static str = "users";
+    }
+
+    struct Order;
+    impl Entity for Order {
+        const TABLE: &'static str = "orders";
+    }
+
+    trait Repository<T: Entity>: Send + Sync {
+        fn table(&self) -> String;
+    }
+
+    struct PostgresRepo<T: Entity> {
+        schema: String,
+        _p: PhantomData<fn() -> T>,
+    }
+
+    #[component]
+    #[scope(Singleton)]
+    #[interface(dyn Repository<T>)]
+    #[instantiate(User)]
+    #[instantiate(Order)]
+    impl<T: Entity> PostgresRepo<T> {
+        fn new(schema: String) -> Self {
+            Self {
+                schema,
+                _p: PhantomData,
+            }
+        }
+    }
+
+    impl<T: Entity> Repository<T> for PostgresRepo<T> {
+        fn table(&self) -> String {
+            format!("{}.{}", self.schema, T::TABLE)
+        }
+    }
+
+    let mut b = CatalogBuilder::new();
+    b.add_family::<PostgresRepoFamily>()
+        .add_value("public".to_owned());
+    b.validate().unwrap();
+    let cat = b.build();
+
+    let users = cat.get_one::<dyn Repository<User>>().unwrap();
+    let orders = cat.get_one::<dyn Repository<Order>>().unwrap();
+    assert_eq!(users.table(), "public.users");
+    assert_eq!(orders.table(), "public.orders");
+
+    // Each instantiation has its own singleton shared by impl and interface
+    let users_impl = cat.get_one::<PostgresRepo<User>>().unwrap();
+    assert!(std::ptr::addr_eq(
+        Arc::as_ptr(&users),
+        Arc::as_ptr(&users_impl)
+    ));
+}
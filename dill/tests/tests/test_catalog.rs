@@ -1,3 +1,4 @@
+use std::assert_matches::assert_matches;
 use std::sync::Arc;
 
 use dill::*;
@@ -371,11 +372,349 @@ fn test_chained_catalog_binds() {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[test]
+fn test_chained_catalog_resolution_after_parent_resolved() {
+    trait A: Send + Sync {
+        fn test(&self) -> String;
+    }
+
+    #[component]
+    #[interface(dyn A)]
+    struct AImpl1;
+    impl A for AImpl1 {
+        fn test(&self) -> String {
+            "aimpl1".to_owned()
+        }
+    }
+
+    #[component]
+    #[interface(dyn A)]
+    struct AImpl2;
+    impl A for AImpl2 {
+        fn test(&self) -> String {
+            "aimpl2".to_owned()
+        }
+    }
+
+    let cat1 = CatalogBuilder::new().add::<AImpl1>().build();
+    assert_eq!(cat1.get_one::<dyn A>().unwrap().test(), "aimpl1");
+
+    // Intermediate catalog that does not add any bindings
+    let cat2 = cat1.builder_chained().add_value(42i32).build();
+    assert_eq!(cat2.get_one::<dyn A>().unwrap().test(), "aimpl1");
+
+    let cat3 = cat2.builder_chained().add::<AImpl2>().build();
+    assert_matches!(
+        cat3.get_one::<dyn A>().err(),
+        Some(InjectionError::Ambiguous(_))
+    );
+
+    let all: Vec<_> = cat3
+        .get::<AllOf<dyn A>>()
+        .unwrap()
+        .iter()
+        .map(|a| a.test())
+        .collect();
+    assert_eq!(all, ["aimpl2", "aimpl1"]);
+
+    // Parents are unaffected
+    assert_eq!(cat1.get::<AllOf<dyn A>>().unwrap().len(), 1);
+    assert_eq!(cat2.get::<AllOf<dyn A>>().unwrap().len(), 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_chained_catalog_shadowing() {
+    trait Clock: Send + Sync {
+        fn name(&self) -> &'static str;
+    }
+
+    #[component]
+    #[interface(dyn Clock)]
+    struct SystemClock;
+    impl Clock for SystemClock {
+        fn name(&self) -> &'static str {
+            "system"
+        }
+    }
+
+    #[component]
+    #[interface(dyn Clock)]
+    struct FakeClock;
+    impl Clock for FakeClock {
+        fn name(&self) -> &'static str {
+            "fake"
+        }
+    }
+
+    #[component]
+    #[interface(dyn Clock)]
+    struct FrozenClock;
+    impl Clock for FrozenClock {
+        fn name(&self) -> &'static str {
+            "frozen"
+        }
+    }
+
+    let names = |cat: &Catalog| -> Vec<&'static str> {
+        cat.get::<AllOf<dyn Clock>>()
+            .unwrap()
+            .iter()
+            .map(|c| c.name())
+            .collect()
+    };
+
+    let base = CatalogBuilder::new().add::<SystemClock>().build();
+
+    // Merging by default
+    let merged = base.builder_chained().add::<FakeClock>().build();
+    assert_matches!(
+        merged.get_one::<dyn Clock>().err(),
+        Some(InjectionError::Ambiguous(_))
+    );
+    assert_eq!(names(&merged), ["fake", "system"]);
+
+    // Nearest catalog wins
+    let shadowed = base
+        .builder_chained()
+        .shadowing()
+        .add::<FakeClock>()
+        .build();
+    assert_eq!(shadowed.get_one::<dyn Clock>().unwrap().name(), "fake");
+    assert_eq!(names(&shadowed), ["fake"]);
+    assert_eq!(shadowed.builders_for::<dyn Clock>().count(), 1);
+
+    // Shadowing only the `OneOf` resolution
+    let shadowed_one_of = base
+        .builder_chained()
+        .shadowing_mode(Shadowing::OneOf)
+        .add::<FakeClock>()
+        .build();
+    assert_eq!(
+        shadowed_one_of.get_one::<dyn Clock>().unwrap().name(),
+        "fake"
+    );
+    assert_eq!(names(&shadowed_one_of), ["fake", "system"]);
+    assert_eq!(shadowed_one_of.builders_for::<dyn Clock>().count(), 2);
+
+    // Catalogs further down the chain see the shadowed view
+    let child = shadowed_one_of.builder_chained().add_value(1i32).build();
+    assert_eq!(child.get_one::<dyn Clock>().unwrap().name(), "fake");
+    assert_eq!(names(&child), ["fake", "system"]);
+
+    let child = shadowed_one_of
+        .builder_chained()
+        .add::<FrozenClock>()
+        .build();
+    assert_matches!(
+        child.get_one::<dyn Clock>().err(),
+        Some(InjectionError::Ambiguous(_))
+    );
+    assert_eq!(names(&child), ["frozen", "fake", "system"]);
+
+    // Interfaces not bound in the shadowing catalog are still inherited
+    let shadowed = base.builder_chained().shadowing().add_value(1i32).build();
+    assert_eq!(shadowed.get_one::<dyn Clock>().unwrap().name(), "system");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_chained_catalog_multiple_parents() {
+    trait Storage: Send + Sync {
+        fn name(&self) -> &'static str;
+    }
+
+    #[component]
+    #[interface(dyn Storage)]
+    struct LocalStorage;
+    impl Storage for LocalStorage {
+        fn name(&self) -> &'static str {
+            "local"
+        }
+    }
+
+    #[component]
+    #[interface(dyn Storage)]
+    struct S3Storage;
+    impl Storage for S3Storage {
+        fn name(&self) -> &'static str {
+            "s3"
+        }
+    }
+
+    #[component]
+    struct Service {
+        storage: Arc<dyn Storage>,
+        name: Arc<String>,
+    }
+
+    let base = CatalogBuilder::new().add_value(1i32).build();
+    let infra = base.builder_chained().add::<LocalStorage>().build();
+    let product = base
+        .builder_chained()
+        .add::<Service>()
+        .add_value(String::from("product"))
+        .build();
+
+    // Lookup across parents
+    let cat = CatalogBuilder::new_chained_multi(&[&infra, &product]).build();
+    let svc = cat.get_one::<Service>().unwrap();
+    assert_eq!(svc.storage.name(), "local");
+    assert_eq!(svc.name.as_str(), "product");
+
+    // Common ancestor is not ambiguous and is listed once
+    assert_eq!(*cat.get_one::<i32>().unwrap(), 1);
+    assert_eq!(cat.builders_for::<i32>().count(), 1);
+    assert_eq!(cat.builders().count(), 4);
+
+    // Weak reference follows all parents
+    let weak = cat.weak_ref();
+    assert_eq!(weak.get_one::<Service>().unwrap().storage.name(), "local");
+
+    // ... and keeps seeing all surviving parents once the child is dropped
+    drop(cat);
+    assert_eq!(weak.get_one::<Service>().unwrap().storage.name(), "local");
+    assert_eq!(weak.get_one::<String>().unwrap().as_str(), "product");
+
+    // ... joining them only once while the joined catalog is held
+    let joined = weak.upgrade();
+    assert_eq!(format!("{:?}", weak.upgrade()), format!("{joined:?}"));
+    drop(joined);
+
+    // Same interface bound in several parents
+    let other = base.builder_chained().add::<S3Storage>().build();
+    let mut b = CatalogBuilder::new_chained_multi(&[&infra]);
+    b.import_from(&other).import_from(&product);
+
+    let mut b_validate = b.clone();
+    b_validate.add::<Service>();
+    assert_matches!(
+        b_validate.validate(),
+        Err(ValidationError { errors }) if matches!(&errors[..], [InjectionError::Ambiguous(_)])
+    );
+
+    let cat = b.build();
+    assert_matches!(
+        cat.get_one::<dyn Storage>().err(),
+        Some(InjectionError::Ambiguous(_))
+    );
+    let names: Vec<_> = cat
+        .get::<AllOf<dyn Storage>>()
+        .unwrap()
+        .iter()
+        .map(|s| s.name())
+        .collect();
+    assert_eq!(names, ["local", "s3"]);
+
+    // Shadowing resolves ambiguity between parents
+    let cat = CatalogBuilder::new_chained_multi(&[&infra, &other])
+        .shadowing()
+        .add::<LocalStorage>()
+        .build();
+    assert_eq!(cat.get_one::<dyn Storage>().unwrap().name(), "local");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_overlay_catalog_resolution() {
+    #[component]
+    struct Subject {
+        name: String,
+    }
+
+    #[component]
+    #[scope(Transaction)]
+    struct Repo {
+        subject: Arc<Subject>,
+        version: Lazy<Arc<i32>>,
+    }
+
+    let base = CatalogBuilder::new()
+        .add::<Subject>()
+        .add::<Repo>()
+        .add_value(1i32)
+        .build();
+
+    let overlay = base
+        .builder_overlay()
+        .add_value(String::from("bob"))
+        .add_value(TransactionCache::new())
+        .build();
+
+    // Values resolve alongside parent builders, including via `Lazy`
+    let repo1 = overlay.get_one::<Repo>().unwrap();
+    assert_eq!(repo1.subject.name, "bob");
+    assert_eq!(*repo1.version.get().unwrap(), 1);
+
+    // Overlay values act as a transaction cache
+    let repo2 = overlay.get_one::<Repo>().unwrap();
+    assert!(Arc::ptr_eq(&repo1, &repo2));
+
+    // Values are listed by introspection
+    assert!(
+        overlay
+            .builders()
+            .any(|b| b.instance_type().id == std::any::TypeId::of::<String>())
+    );
+    assert_eq!(overlay.builders_for::<String>().count(), 1);
+
+    // Values behave like ones registered in a chained catalog
+    let weak = overlay.weak_ref();
+    assert_eq!(weak.upgrade().get_one::<String>().unwrap().as_str(), "bob");
+
+    // Parent is unaffected
+    assert_matches!(
+        base.get_one::<String>(),
+        Err(InjectionError::Unregistered(_))
+    );
+}
+
+#[test]
+fn test_overlay_catalog_chaining() {
+    let base = CatalogBuilder::new().add_value(1i32).build();
+
+    let overlay = base.builder_overlay().add_value(2i32).build();
+    assert_matches!(
+        overlay.get_one::<i32>().err(),
+        Some(InjectionError::Ambiguous(_))
+    );
+    assert_eq!(
+        overlay
+            .get::<AllOf<i32>>()
+            .unwrap()
+            .iter()
+            .map(|v| **v)
+            .collect::<Vec<_>>(),
+        [2, 1]
+    );
+
+    // Catalogs chained on top of an overlay see its values
+    let overlay = base.builder_overlay().add_value(String::from("a")).build();
+    let chained = overlay.builder_chained().add_value(3u64).build();
+    assert_eq!(chained.get_one::<String>().unwrap().as_str(), "a");
+    assert_eq!(*chained.get_one::<u64>().unwrap(), 3);
+
+    let overlay2 = chained.builder_overlay().add_value(4u8).build();
+    assert_eq!(overlay2.get_one::<String>().unwrap().as_str(), "a");
+    assert_eq!(*overlay2.get_one::<i32>().unwrap(), 1);
+    assert_eq!(*overlay2.get_one::<u8>().unwrap(), 4);
+}
+
+#[test]
+#[should_panic]
+fn test_overlay_catalog_duplicate_value_panics() {
+    let base = CatalogBuilder::new().build();
+    base.builder_overlay().add_value(1i32).add_value(2i32);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 #[cfg(feature = "tokio")]
 #[tokio::test]
 async fn test_catalog_scope() {
-    use std::assert_matches::assert_matches;
-
     let cat1 = Catalog::builder().add_value(1i32).build();
 
     let cat = cat1.clone();
@@ -562,3 +901,219 @@ fn test_catalog_binds_interfaces_for_builder_does_not_require_an_explicit_bind()
         _ => panic!("Expected an ambiguous error"),
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_adapt_interface() {
+    trait StorageV1: Send + Sync {
+        fn get(&self) -> String;
+    }
+
+    trait StorageV2: Send + Sync {
+        fn read(&self, key: &str) -> String;
+    }
+
+    #[component]
+    #[scope(Singleton)]
+    #[interface(dyn StorageV2)]
+    struct StorageImpl;
+    impl StorageV2 for StorageImpl {
+        fn read(&self, key: &str) -> String {
+            format!("v2::{key}")
+        }
+    }
+
+    struct Adapter(Arc<dyn StorageV2>);
+    impl From<Arc<dyn StorageV2>> for Adapter {
+        fn from(inner: Arc<dyn StorageV2>) -> Self {
+            Self(inner)
+        }
+    }
+    impl StorageV1 for Adapter {
+        fn get(&self) -> String {
+            self.0.read("default")
+        }
+    }
+
+    let cat = CatalogBuilder::new()
+        .add::<StorageImpl>()
+        .adapt::<dyn StorageV1, dyn StorageV2, Adapter>()
+        .build();
+
+    let v1 = cat.get_one::<dyn StorageV1>().unwrap();
+    assert_eq!(v1.get(), "v2::default");
+
+    // Adapter wraps the same cached instance
+    let v2 = cat.get_one::<dyn StorageV2>().unwrap();
+    let adapter = cat.get_one::<Adapter>().unwrap();
+    assert!(Arc::ptr_eq(&adapter.0, &v2));
+
+    // Adapter edge is visible in introspection
+    let b = cat.builders_for::<dyn StorageV1>().next().unwrap();
+    assert_eq!(b.instance_type(), TypeInfo::of::<Adapter>());
+    assert_eq!(b.interfaces_get_all(), [TypeInfo::of::<dyn StorageV1>()]);
+    assert_eq!(
+        b.dependencies_get_all(),
+        [DependencyInfo::of::<dyn StorageV2, OneOf<dyn StorageV2>>()]
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_add_factory() {
+    trait Greeter: Send + Sync {
+        fn greet(&self) -> String;
+    }
+
+    #[component]
+    #[interface(dyn Greeter)]
+    struct GreeterImpl;
+    impl Greeter for GreeterImpl {
+        fn greet(&self) -> String {
+            "hello".to_owned()
+        }
+    }
+
+    struct Greeting {
+        text: String,
+    }
+
+    let cat = CatalogBuilder::new()
+        .add::<GreeterImpl>()
+        .add_value(3_usize)
+        .add_factory(
+            |greeter: Arc<dyn Greeter>, name: Option<Arc<String>>, all: Vec<Arc<usize>>| Greeting {
+                text: format!(
+                    "{} {}{}",
+                    greeter.greet(),
+                    name.map(|n| n.to_string())
+                        .unwrap_or("anonymous".to_owned()),
+                    "!".repeat(*all[0]),
+                ),
+            },
+        )
+        .build();
+
+    assert_eq!(
+        cat.get_one::<Greeting>().unwrap().text,
+        "hello anonymous!!!"
+    );
+
+    // Transient by default
+    let g1 = cat.get_one::<Greeting>().unwrap();
+    let g2 = cat.get_one::<Greeting>().unwrap();
+    assert!(!Arc::ptr_eq(&g1, &g2));
+
+    // Arguments are visible in introspection
+    let b = cat.builders_for::<Greeting>().next().unwrap();
+    assert_eq!(b.scope_type(), TypeInfo::of::<Transient>());
+    assert_eq!(
+        b.dependencies_get_all(),
+        [
+            DependencyInfo::of::<dyn Greeter, OneOf<dyn Greeter>>(),
+            DependencyInfo::of::<String, Maybe<OneOf<String>>>(),
+            DependencyInfo::of::<usize, AllOf<usize>>(),
+        ]
+    );
+
+    // Missing dependencies are reported
+    let cat = CatalogBuilder::new()
+        .add_factory(|greeter: Arc<dyn Greeter>| Greeting {
+            text: greeter.greet(),
+        })
+        .build();
+
+    assert_matches!(
+        cat.get_one::<Greeting>().err(),
+        Some(InjectionError::Unregistered(_))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_add_factory_scoped() {
+    struct Config(usize);
+    struct Service(usize);
+
+    let cat = CatalogBuilder::new()
+        .add_value(Config(1))
+        .add_factory_scoped(Singleton::new(), |c: Arc<Config>| Service(c.0 + 1))
+        .build();
+
+    let b = cat.builders_for::<Service>().next().unwrap();
+    assert_eq!(b.scope_type(), TypeInfo::of::<Singleton>());
+
+    let inst1 = cat.get_one::<Service>().unwrap();
+    let inst2 = cat.get_one::<Service>().unwrap();
+    assert_eq!(inst1.0, 2);
+    assert!(Arc::ptr_eq(&inst1, &inst2));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_call() {
+    trait Repo: Send + Sync {
+        fn get(&self) -> String;
+    }
+
+    #[component]
+    #[interface(dyn Repo)]
+    struct RepoImpl;
+    impl Repo for RepoImpl {
+        fn get(&self) -> String {
+            "foo".to_owned()
+        }
+    }
+
+    let cat = CatalogBuilder::new()
+        .add::<RepoImpl>()
+        .add_value(2_usize)
+        .build();
+
+    // Closures can borrow from the caller
+    let prefix = String::from(">");
+    let res = cat.call(
+        |repo: Arc<dyn Repo>, count: Arc<usize>, missing: Option<Arc<String>>| {
+            assert!(missing.is_none());
+            format!("{prefix}{}", repo.get().repeat(*count))
+        },
+    );
+    assert_eq!(res.unwrap(), ">foofoo");
+
+    fn handler(repo: Arc<dyn Repo>) -> String {
+        repo.get()
+    }
+    assert_eq!(cat.call(handler).unwrap(), "foo");
+    assert_eq!(
+        FactoryFn::dependencies_get_all(&handler),
+        [DependencyInfo::of::<dyn Repo, OneOf<dyn Repo>>()]
+    );
+
+    // The function is not called if arguments can't be resolved
+    let res = cat.call(|_: Arc<String>| -> () { unreachable!() });
+    assert_matches!(res, Err(InjectionError::Unregistered(_)));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[tokio::test]
+async fn test_call_async() {
+    let cat = CatalogBuilder::new().add_value(String::from("foo")).build();
+
+    let res = cat
+        .call_async(|name: Arc<String>| async move {
+            tokio::task::yield_now().await;
+            name.len()
+        })
+        .await;
+    assert_eq!(res.unwrap(), 3);
+
+    let res = cat
+        .call_async(|_: Arc<usize>| async move { unreachable!() })
+        .await;
+    assert_matches!(res, Err(InjectionError::Unregistered(_)));
+}
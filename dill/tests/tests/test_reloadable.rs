@@ -0,0 +1,114 @@
+use std::assert_matches::assert_matches;
+use std::sync::Arc;
+
+use dill::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_reload_value() {
+    struct Config {
+        level: u32,
+    }
+
+    #[component]
+    #[scope(Singleton)]
+    struct Service {
+        config: Reloadable<Arc<Config>>,
+    }
+
+    let cat = CatalogBuilder::new()
+        .add_reloadable(Arc::new(Config { level: 1 }))
+        .add::<Service>()
+        .build();
+
+    let svc = cat.get_one::<Service>().unwrap();
+    let old_config = svc.config.get().unwrap();
+    assert_eq!(old_config.level, 1);
+
+    let old_config_weak = Arc::downgrade(&old_config);
+    drop(old_config);
+    cat.reload::<Config>(Arc::new(Config { level: 2 })).unwrap();
+
+    // Handle picks up the new instance while the old one is dropped
+    assert_eq!(svc.config.get().unwrap().level, 2);
+    assert_eq!(cat.get_one::<Config>().unwrap().level, 2);
+    assert!(old_config_weak.upgrade().is_none());
+
+    // Singleton itself is not re-created
+    assert!(Arc::ptr_eq(&svc, &cat.get_one::<Service>().unwrap()));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_reload_component() {
+    trait Greeter: Send + Sync {
+        fn greet(&self) -> String;
+    }
+
+    #[component]
+    #[interface(dyn Greeter)]
+    #[scope(Singleton)]
+    struct GreeterImpl {
+        #[component(explicit)]
+        greeting: String,
+    }
+    impl Greeter for GreeterImpl {
+        fn greet(&self) -> String {
+            self.greeting.clone()
+        }
+    }
+
+    #[component]
+    struct Client {
+        greeter: Reloadable<Arc<dyn Greeter>>,
+    }
+
+    let base = CatalogBuilder::new()
+        .add_reloadable(GreeterImpl::builder("hello".to_owned()))
+        .add::<Client>()
+        .build();
+    let cat = base.builder_chained().add_value(1i32).build();
+
+    let client = cat.get_one::<Client>().unwrap();
+    let old_greeter = cat.get_one::<dyn Greeter>().unwrap();
+    assert_eq!(client.greeter.get().unwrap().greet(), "hello");
+
+    // Reloading via a chained catalog affects the catalog that registered it
+    cat.reload::<GreeterImpl>(GreeterImpl::builder("hi".to_owned()))
+        .unwrap();
+
+    assert_eq!(client.greeter.get().unwrap().greet(), "hi");
+    assert_eq!(base.get_one::<dyn Greeter>().unwrap().greet(), "hi");
+
+    // Old singleton is alive only while held
+    assert_eq!(old_greeter.greet(), "hello");
+    let old_greeter_weak = Arc::downgrade(&old_greeter);
+    drop(old_greeter);
+    assert!(old_greeter_weak.upgrade().is_none());
+
+    // Dependency is visible to introspection
+    let deps = cat
+        .builders_for::<Client>()
+        .next()
+        .unwrap()
+        .dependencies_get_all();
+    assert_eq!(
+        deps[0].spec.name,
+        std::any::type_name::<specs::Reloadable<OneOf<dyn Greeter>>>()
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_reload_unregistered() {
+    let cat = CatalogBuilder::new().add_value(1i32).build();
+
+    assert_matches!(
+        cat.reload::<i32>(Arc::new(2i32)),
+        Err(InjectionError::Unregistered(_))
+    );
+    assert_eq!(*cat.get_one::<i32>().unwrap(), 1);
+}
@@ -195,6 +195,122 @@ fn test_chained_singleton() {
     assert_eq!(inst_b_2.test(), "bimpl::unique");
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_singleton_cycle() {
+    #[dill::component]
+    #[dill::scope(dill::Singleton)]
+    struct A {
+        #[allow(dead_code)]
+        b: Arc<B>,
+    }
+
+    #[dill::component]
+    struct B {
+        #[allow(dead_code)]
+        a: Arc<A>,
+    }
+
+    let cat = dill::Catalog::builder().add::<A>().add::<B>().build();
+
+    let res = cat.get_one::<A>();
+    pretty_assertions::assert_eq!(
+        res.err().unwrap().to_string(),
+        indoc::indoc!(
+            r#"
+            Dependency cycle: unit::tests::test_scopes::test_singleton_cycle::A
+            Injection stack:
+              0: Resolve: dill::specs::OneOf<unit::tests::test_scopes::test_singleton_cycle::A>
+              1: Build:   unit::tests::test_scopes::test_singleton_cycle::A <dill::scopes::Singleton>
+              2: Resolve: dill::specs::OneOf<unit::tests::test_scopes::test_singleton_cycle::B>
+              3: Build:   unit::tests::test_scopes::test_singleton_cycle::B <dill::scopes::Transient>
+              4: Resolve: dill::specs::OneOf<unit::tests::test_scopes::test_singleton_cycle::A>
+            "#
+        )
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_singleton_reentrant_resolution() {
+    struct A {
+        reentrant: Result<(), dill::InjectionError>,
+    }
+
+    #[dill::component]
+    #[dill::scope(dill::Singleton)]
+    impl A {
+        fn new(catalog: &dill::Catalog) -> Self {
+            Self {
+                reentrant: catalog.get_one::<A>().map(|_| ()),
+            }
+        }
+    }
+
+    let cat = dill::Catalog::builder().add::<A>().build();
+
+    let a = cat.get_one::<A>().unwrap();
+    assert_matches!(a.reentrant, Err(dill::InjectionError::Cycle(_)));
+
+    // Singleton is not poisoned by the failed nested attempt
+    let a2 = cat.get_one::<A>().unwrap();
+    assert!(Arc::ptr_eq(&a, &a2));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_transaction_reentrant_resolution() {
+    struct A {
+        reentrant: Result<(), dill::InjectionError>,
+    }
+
+    #[dill::component]
+    #[dill::scope(dill::Transaction)]
+    impl A {
+        fn new(catalog: &dill::Catalog) -> Self {
+            Self {
+                reentrant: catalog.get_one::<A>().map(|_| ()),
+            }
+        }
+    }
+
+    let base = dill::Catalog::builder().add::<A>().build();
+    let cat = base
+        .builder_chained()
+        .add_value(dill::TransactionCache::new())
+        .build();
+
+    let a = cat.get_one::<A>().unwrap();
+    assert_matches!(a.reentrant, Err(dill::InjectionError::Cycle(_)));
+
+    let a2 = cat.get_one::<A>().unwrap();
+    assert!(Arc::ptr_eq(&a, &a2));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_singleton_retries_after_error() {
+    #[dill::component]
+    #[dill::scope(dill::Singleton)]
+    struct A {
+        #[allow(dead_code)]
+        val: i32,
+    }
+
+    let cat = dill::Catalog::builder().add::<A>().build();
+    assert_matches!(
+        cat.get_one::<A>().err(),
+        Some(dill::InjectionError::Unregistered(_))
+    );
+
+    let cat = cat.builder_chained().add_value(42i32).build();
+    assert_eq!(cat.get_one::<A>().unwrap().val, 42);
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Transaction
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -311,3 +427,211 @@ fn test_scope_transaction() {
         assert_eq!(a.test(), "::");
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_scope_transaction_cache_management() {
+    use dill::cache::Cache;
+
+    trait A: Send + Sync {}
+
+    #[dill::component]
+    #[dill::interface(dyn A)]
+    #[dill::scope(dill::scopes::Transaction)]
+    struct AImpl;
+    impl A for AImpl {}
+
+    #[dill::component]
+    #[dill::scope(dill::scopes::Transaction)]
+    struct B;
+
+    let base = dill::Catalog::builder().add::<AImpl>().add::<B>().build();
+
+    let tx = base
+        .builder_chained()
+        .add_value(dill::scopes::TransactionCache::new())
+        .build();
+
+    let cache = tx.get_one::<dill::scopes::TransactionCache>().unwrap();
+    assert!(cache.is_empty());
+
+    let a1 = tx.get_one::<dyn A>().unwrap();
+    let b1 = tx.get_one::<B>().unwrap();
+
+    let mut cached: Vec<_> = cache
+        .entries()
+        .into_iter()
+        .map(|e| e.instance_type)
+        .collect();
+    cached.sort_by_key(|t| t.name);
+    assert_eq!(
+        cached,
+        [dill::TypeInfo::of::<AImpl>(), dill::TypeInfo::of::<B>()]
+    );
+
+    // Evict by interface
+    tx.evict::<dyn A>();
+    assert_eq!(cache.len(), 1);
+
+    let a2 = tx.get_one::<dyn A>().unwrap();
+    let b2 = tx.get_one::<B>().unwrap();
+    assert!(!Arc::ptr_eq(&a1, &a2));
+    assert!(Arc::ptr_eq(&b1, &b2));
+
+    // Drop everything
+    cache.clear();
+    assert!(cache.is_empty());
+
+    let b3 = tx.get_one::<B>().unwrap();
+    assert!(!Arc::ptr_eq(&b1, &b3));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Keyed
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_scope_keyed() {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct TenantId(String);
+
+    trait Pool: Send + Sync {
+        fn tenant(&self) -> String;
+    }
+
+    #[dill::component]
+    #[dill::interface(dyn Pool)]
+    #[dill::scope(dill::scopes::Keyed<TenantId>)]
+    struct PoolImpl {
+        tenant: TenantId,
+    }
+
+    impl Pool for PoolImpl {
+        fn tenant(&self) -> String {
+            self.tenant.0.clone()
+        }
+    }
+
+    let cat = dill::Catalog::builder().add::<PoolImpl>().build();
+
+    // Error if resolved without a key
+    assert_matches!(
+        cat.get_one::<dyn Pool>().err(),
+        Some(dill::InjectionError::Unregistered(_))
+    );
+
+    let a1 = cat
+        .get_keyed::<dill::OneOf<dyn Pool>>(TenantId("a".into()))
+        .unwrap();
+    let a2 = cat
+        .get_keyed::<dill::OneOf<dyn Pool>>(TenantId("a".into()))
+        .unwrap();
+    let b1 = cat
+        .get_keyed::<dill::OneOf<dyn Pool>>(TenantId("b".into()))
+        .unwrap();
+
+    assert!(Arc::ptr_eq(&a1, &a2));
+    assert!(!Arc::ptr_eq(&a1, &b1));
+    assert_eq!(a1.tenant(), "a");
+    assert_eq!(b1.tenant(), "b");
+
+    // Evicting one key leaves others intact
+    cat.evict_keyed::<PoolImpl>(TenantId("a".into()));
+
+    let a3 = cat
+        .get_keyed::<dill::OneOf<dyn Pool>>(TenantId("a".into()))
+        .unwrap();
+    let b2 = cat
+        .get_keyed::<dill::OneOf<dyn Pool>>(TenantId("b".into()))
+        .unwrap();
+
+    assert!(!Arc::ptr_eq(&a1, &a3));
+    assert!(Arc::ptr_eq(&b1, &b2));
+    assert_eq!(a3.tenant(), "a");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_scope_keyed_from_impl() {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct TenantId(u32);
+
+    struct Pool {
+        tenant: u32,
+    }
+
+    #[dill::component]
+    #[dill::scope(dill::scopes::Keyed<TenantId>)]
+    impl Pool {
+        fn new(tenant: TenantId) -> Self {
+            Self { tenant: tenant.0 }
+        }
+    }
+
+    let cat = dill::Catalog::builder().add::<Pool>().build();
+
+    // Key can also be provided by chaining a catalog
+    let tenant_cat = cat.builder_chained().add_value(TenantId(1)).build();
+    let p1 = tenant_cat.get_one::<Pool>().unwrap();
+    let p2 = cat.get_keyed::<dill::OneOf<Pool>>(TenantId(1)).unwrap();
+
+    assert!(Arc::ptr_eq(&p1, &p2));
+    assert_eq!(p1.tenant, 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_scope_keyed_reentrant_resolution() {
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct TenantId(u32);
+
+    static BASE: std::sync::OnceLock<dill::Catalog> = std::sync::OnceLock::new();
+
+    struct Pool {
+        tenant: u32,
+        reentrant: Result<(), dill::InjectionError>,
+        other: Option<Arc<Pool>>,
+    }
+
+    #[dill::component]
+    #[dill::scope(dill::scopes::Keyed<TenantId>)]
+    impl Pool {
+        fn new(tenant: TenantId, catalog: &dill::Catalog) -> Self {
+            // Instances of other keys can be created meanwhile on other threads
+            let other = (tenant.0 == 1).then(|| {
+                std::thread::scope(|s| {
+                    s.spawn(|| {
+                        BASE.get()
+                            .unwrap()
+                            .get_keyed::<dill::OneOf<Pool>>(TenantId(2))
+                            .unwrap()
+                    })
+                    .join()
+                    .unwrap()
+                })
+            });
+
+            Self {
+                tenant: tenant.0,
+                reentrant: catalog.get_one::<Pool>().map(|_| ()),
+                other,
+            }
+        }
+    }
+
+    let cat = BASE.get_or_init(|| dill::Catalog::builder().add::<Pool>().build());
+
+    let p1 = cat.get_keyed::<dill::OneOf<Pool>>(TenantId(1)).unwrap();
+    assert_eq!(p1.tenant, 1);
+    assert_matches!(p1.reentrant, Err(dill::InjectionError::Cycle(_)));
+
+    let p2 = cat.get_keyed::<dill::OneOf<Pool>>(TenantId(2)).unwrap();
+    assert!(Arc::ptr_eq(p1.other.as_ref().unwrap(), &p2));
+
+    // Slot is not poisoned by the failed nested attempt
+    let p1_again = cat.get_keyed::<dill::OneOf<Pool>>(TenantId(1)).unwrap();
+    assert!(Arc::ptr_eq(&p1, &p1_again));
+}
@@ -389,3 +389,397 @@ fn test_lazy_derive() {
     let b = cat.get_one::<B>().unwrap();
     assert_eq!(b.test(), "A");
 }
+
+#[test]
+fn test_owned() {
+    #[component]
+    struct Parser {
+        prefix: String,
+    }
+
+    impl Parser {
+        fn parse(&mut self, s: &str) -> String {
+            self.prefix.push_str(s);
+            self.prefix.clone()
+        }
+    }
+
+    #[component]
+    #[scope(Singleton)]
+    struct Consumer {
+        parser: Box<Parser>,
+    }
+
+    let cat = Catalog::builder()
+        .add::<Parser>()
+        .add::<Consumer>()
+        .add_value("p:".to_owned())
+        .build();
+
+    let mut parser = cat.get::<Owned<Parser>>().unwrap();
+    assert_eq!(parser.parse("a"), "p:a");
+    assert_eq!(parser.parse("b"), "p:ab");
+
+    // Every resolution creates a fresh instance
+    let mut parser = cat.get::<Owned<Parser>>().unwrap();
+    assert_eq!(parser.parse("c"), "p:c");
+
+    let consumer = cat.get_one::<Consumer>().unwrap();
+    assert_eq!(consumer.parser.prefix, "p:");
+
+    assert_eq!(
+        cat.builders_for::<Consumer>()
+            .next()
+            .unwrap()
+            .dependencies_get_all(),
+        [DependencyInfo::of::<Parser, Owned<Parser>>()]
+    );
+
+    // Shared instances cannot be owned
+    assert_matches!(
+        cat.get::<Owned<String>>(),
+        Err(InjectionError::Ownership(e)) if e.dep_type == TypeInfo::of::<String>()
+    );
+}
+
+#[test]
+fn test_weak() {
+    #[component]
+    #[scope(Singleton)]
+    struct Config;
+
+    #[component]
+    struct Service {
+        config: std::sync::Weak<Config>,
+    }
+
+    let cat = Catalog::builder().add::<Config>().add::<Service>().build();
+
+    let config = cat.get_one::<Config>().unwrap();
+    let service = cat.get_one::<Service>().unwrap();
+    assert!(Arc::ptr_eq(&service.config.upgrade().unwrap(), &config));
+
+    assert_eq!(
+        cat.builders_for::<Service>()
+            .next()
+            .unwrap()
+            .dependencies_get_all(),
+        [DependencyInfo::of::<Config, WeakOf<Config>>()]
+    );
+}
+
+#[test]
+fn test_weak_non_sync_path_is_value() {
+    mod other {
+        #[derive(Clone)]
+        pub struct Weak<T>(pub T);
+    }
+
+    #[component]
+    struct Service {
+        value: other::Weak<i32>,
+    }
+
+    let cat = Catalog::builder()
+        .add_value(other::Weak(5))
+        .add::<Service>()
+        .build();
+
+    assert_eq!(cat.get_one::<Service>().unwrap().value.0, 5);
+}
+
+#[test]
+fn test_weak_cyclic() {
+    use std::sync::Weak;
+
+    #[component]
+    #[scope(Singleton)]
+    struct Parent {
+        child: Arc<Child>,
+    }
+
+    #[component]
+    #[scope(Singleton)]
+    struct Child {
+        parent: Weak<Parent>,
+    }
+
+    let cat = Catalog::builder().add::<Parent>().add::<Child>().build();
+
+    let parent = cat.get_one::<Parent>().unwrap();
+    let child = cat.get_one::<Child>().unwrap();
+    assert!(Arc::ptr_eq(&parent.child, &child));
+    assert!(Arc::ptr_eq(&child.parent.upgrade().unwrap(), &parent));
+
+    // No ownership cycle
+    let parent_weak = Arc::downgrade(&parent);
+    drop(parent);
+    drop(child);
+    drop(cat);
+    assert!(parent_weak.upgrade().is_none());
+}
+
+#[test]
+fn test_weak_cyclic_error() {
+    use std::sync::Weak;
+
+    #[allow(dead_code)]
+    #[component]
+    struct Parent {
+        child: Arc<Child>,
+    }
+
+    #[allow(dead_code)]
+    #[component]
+    struct Child {
+        parent: Weak<Parent>,
+        missing: Arc<u32>,
+    }
+
+    let cat = Catalog::builder().add::<Parent>().add::<Child>().build();
+
+    assert_matches!(
+        cat.get_one::<Parent>().err(),
+        Some(InjectionError::Unregistered(e)) if e.dep_type == TypeInfo::of::<u32>()
+    );
+}
+
+#[test]
+fn test_weak_cyclic_iface() {
+    use std::sync::Weak;
+
+    trait Parent: Send + Sync {
+        fn name(&self) -> &str;
+    }
+
+    #[component]
+    #[interface(dyn Parent)]
+    #[scope(Singleton)]
+    struct ParentImpl {
+        child: Arc<Child>,
+    }
+    impl Parent for ParentImpl {
+        fn name(&self) -> &str {
+            "parent"
+        }
+    }
+
+    #[component]
+    #[scope(Singleton)]
+    struct Child {
+        parent: Weak<dyn Parent>,
+    }
+
+    let cat = Catalog::builder()
+        .add::<ParentImpl>()
+        .add::<Child>()
+        .build();
+
+    let parent = cat.get_one::<ParentImpl>().unwrap();
+    assert!(Arc::ptr_eq(&parent.child, &cat.get_one::<Child>().unwrap()));
+    assert_eq!(parent.child.parent.upgrade().unwrap().name(), "parent");
+}
+
+#[test]
+fn test_weak_cyclic_error_constructs_once() {
+    use std::sync::Weak;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static CONSTRUCTED: AtomicUsize = AtomicUsize::new(0);
+
+    #[allow(dead_code)]
+    #[component]
+    struct Parent {
+        sibling: Arc<Sibling>,
+        child: Arc<Child>,
+        missing: Arc<u32>,
+    }
+
+    struct Sibling;
+
+    #[component]
+    impl Sibling {
+        pub fn new() -> Self {
+            CONSTRUCTED.fetch_add(1, Ordering::Relaxed);
+            Self
+        }
+    }
+
+    #[allow(dead_code)]
+    #[component]
+    struct Child {
+        parent: Weak<Parent>,
+    }
+
+    let cat = Catalog::builder()
+        .add::<Parent>()
+        .add::<Sibling>()
+        .add::<Child>()
+        .build();
+
+    assert_matches!(
+        cat.get_one::<Parent>().err(),
+        Some(InjectionError::Unregistered(e)) if e.dep_type == TypeInfo::of::<u32>()
+    );
+    assert_eq!(CONSTRUCTED.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn test_owned_box() {
+    trait Parser: Send + Sync {
+        fn parse(&mut self, s: &str) -> String;
+    }
+
+    #[component]
+    #[interface(dyn Parser)]
+    struct ParserImpl {
+        buf: String,
+    }
+
+    impl Parser for ParserImpl {
+        fn parse(&mut self, s: &str) -> String {
+            self.buf.push_str(s);
+            self.buf.clone()
+        }
+    }
+
+    #[component]
+    struct Consumer {
+        parser: Box<dyn Parser>,
+    }
+
+    let cat = Catalog::builder()
+        .add::<ParserImpl>()
+        .add::<Consumer>()
+        .add_value("p:".to_owned())
+        .build();
+
+    let mut parser = cat.get::<OwnedBox<dyn Parser>>().unwrap();
+    assert_eq!(parser.parse("a"), "p:a");
+    assert_eq!(parser.parse("b"), "p:ab");
+
+    let consumer = cat.get::<Owned<Consumer>>().unwrap();
+    let mut parser = consumer.parser;
+    assert_eq!(parser.parse("c"), "p:c");
+
+    assert_eq!(
+        cat.builders_for::<Consumer>()
+            .next()
+            .unwrap()
+            .dependencies_get_all(),
+        [DependencyInfo::of::<dyn Parser, OwnedBox<dyn Parser>>()]
+    );
+
+    // Shared instances cannot be owned
+    assert_matches!(
+        cat.get::<OwnedBox<String>>().err(),
+        Some(InjectionError::Ownership(e)) if e.dep_type == TypeInfo::of::<String>()
+    );
+}
+
+#[test]
+fn test_fallible() {
+    #[component]
+    struct Consumer {
+        name: Result<Arc<String>, InjectionError>,
+    }
+
+    let cat = Catalog::builder().add::<Consumer>().build();
+    let consumer = cat.get_one::<Consumer>().unwrap();
+    assert_matches!(&consumer.name, Err(InjectionError::Unregistered(_)));
+
+    let cat = cat.builder_chained().add_value("foo".to_owned()).build();
+    let consumer = cat.get_one::<Consumer>().unwrap();
+    assert_eq!(consumer.name.as_ref().unwrap().as_str(), "foo");
+
+    assert_eq!(
+        cat.builders_for::<Consumer>()
+            .next()
+            .unwrap()
+            .dependencies_get_all(),
+        [DependencyInfo::of::<String, Fallible<OneOf<String>>>()]
+    );
+}
+
+#[test]
+fn test_nested_shapes() {
+    trait Plugin: Send + Sync {
+        fn name(&self) -> String;
+    }
+
+    #[component]
+    #[interface(dyn Plugin)]
+    struct PluginImpl;
+    impl Plugin for PluginImpl {
+        fn name(&self) -> String {
+            "plugin".to_owned()
+        }
+    }
+
+    #[component]
+    struct Consumer {
+        lazy_all: dill::Lazy<Vec<Arc<dyn Plugin>>>,
+        lazy_maybe: dill::Lazy<Option<Arc<dyn Plugin>>>,
+        lazy_value: dill::Lazy<String>,
+        maybe_all: Option<Vec<Arc<dyn Plugin>>>,
+        values: Vec<u32>,
+        maybe_values: Option<Vec<u32>>,
+        result_value: Result<String, InjectionError>,
+        result_lazy: Result<dill::Lazy<Arc<dyn Plugin>>, InjectionError>,
+    }
+
+    // Values of the same type are collected from a chain of catalogs
+    let cat = Catalog::builder()
+        .add::<PluginImpl>()
+        .add::<Consumer>()
+        .add_value("foo".to_owned())
+        .add_value(1_u32)
+        .build();
+    let cat = cat.builder_chained().add_value(2_u32).build();
+
+    let c = cat.get_one::<Consumer>().unwrap();
+    assert_eq!(c.lazy_all.get().unwrap()[0].name(), "plugin");
+    assert_eq!(c.lazy_maybe.get().unwrap().unwrap().name(), "plugin");
+    assert_eq!(c.lazy_value.get().unwrap(), "foo");
+    assert_eq!(c.maybe_all.as_ref().unwrap().len(), 1);
+    assert_eq!(c.values, [2, 1]);
+    assert_eq!(c.maybe_values, Some(vec![2, 1]));
+    assert_eq!(c.result_value.as_ref().unwrap(), "foo");
+    assert_eq!(
+        c.result_lazy.as_ref().unwrap().get().unwrap().name(),
+        "plugin"
+    );
+
+    assert_eq!(
+        cat.builders_for::<Consumer>()
+            .next()
+            .unwrap()
+            .dependencies_get_all(),
+        [
+            DependencyInfo::of::<dyn Plugin, specs::Lazy<AllOf<dyn Plugin>>>(),
+            DependencyInfo::of::<dyn Plugin, specs::Lazy<Maybe<OneOf<dyn Plugin>>>>(),
+            DependencyInfo::of::<String, specs::Lazy<OneOf<String>>>(),
+            DependencyInfo::of::<dyn Plugin, Maybe<AllOf<dyn Plugin>>>(),
+            DependencyInfo::of::<u32, AllOf<u32>>(),
+            DependencyInfo::of::<u32, Maybe<AllOf<u32>>>(),
+            DependencyInfo::of::<String, Fallible<OneOf<String>>>(),
+            DependencyInfo::of::<dyn Plugin, Fallible<specs::Lazy<OneOf<dyn Plugin>>>>(),
+        ]
+    );
+
+    // Optional and fallible shapes are valid without registrations
+    let mut b = Catalog::builder();
+    b.add::<Consumer>().add_value("foo".to_owned());
+    assert_matches!(b.validate(), Ok(()));
+
+    let mut b = Catalog::builder();
+    b.add::<Consumer>();
+    assert_matches!(
+        b.validate(),
+        Err(ValidationError { errors }) if matches!(
+            &errors[..],
+            [InjectionError::Unregistered(u)]
+            if u.dep_type == TypeInfo::of::<String>()
+        )
+    );
+}
@@ -1,3 +1,4 @@
+use dill::cache::Cache;
 use dill::*;
 
 #[test]
@@ -60,3 +61,153 @@ fn test_clone_to_threads() {
     assert_eq!(res1, "foo");
     assert_eq!(res2, "foo");
 }
+
+#[test]
+fn test_singleton_concurrent_init() {
+    static INSTANCE_COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+    struct A;
+
+    #[component]
+    #[scope(Singleton)]
+    impl A {
+        fn new() -> Self {
+            INSTANCE_COUNT.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            Self
+        }
+    }
+
+    let cat = CatalogBuilder::new().add::<A>().build();
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let cat = cat.clone();
+            std::thread::spawn(move || cat.get_one::<A>().unwrap())
+        })
+        .collect();
+
+    let instances: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    assert_eq!(INSTANCE_COUNT.load(std::sync::atomic::Ordering::SeqCst), 1);
+    for inst in &instances[1..] {
+        assert!(std::sync::Arc::ptr_eq(&instances[0], inst));
+    }
+}
+
+#[test]
+fn test_singleton_concurrent_reads() {
+    #[component]
+    #[scope(Singleton)]
+    struct A;
+
+    let cat = CatalogBuilder::new().add::<A>().build();
+    let first = cat.get_one::<A>().unwrap();
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let cat = cat.clone();
+            let first = first.clone();
+            std::thread::spawn(move || {
+                for _ in 0..1000 {
+                    assert!(std::sync::Arc::ptr_eq(&first, &cat.get_one::<A>().unwrap()));
+                }
+            })
+        })
+        .collect();
+
+    for h in handles {
+        h.join().unwrap();
+    }
+}
+
+#[test]
+fn test_transaction_cache_concurrent_reads() {
+    #[component]
+    #[scope(Transaction)]
+    struct A;
+
+    #[component]
+    #[scope(Transaction)]
+    struct B;
+
+    #[component]
+    #[scope(Transaction)]
+    struct C;
+
+    let base = CatalogBuilder::new()
+        .add::<A>()
+        .add::<B>()
+        .add::<C>()
+        .build();
+    let cat = base
+        .builder_chained()
+        .add_value(TransactionCache::new())
+        .build();
+
+    let a = cat.get_one::<A>().unwrap();
+    let b = cat.get_one::<B>().unwrap();
+    let c = cat.get_one::<C>().unwrap();
+
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let cat = cat.clone();
+            let (a, b, c) = (a.clone(), b.clone(), c.clone());
+            std::thread::spawn(move || {
+                for _ in 0..1000 {
+                    assert!(std::sync::Arc::ptr_eq(&a, &cat.get_one::<A>().unwrap()));
+                    assert!(std::sync::Arc::ptr_eq(&b, &cat.get_one::<B>().unwrap()));
+                    assert!(std::sync::Arc::ptr_eq(&c, &cat.get_one::<C>().unwrap()));
+                }
+            })
+        })
+        .collect();
+
+    for h in handles {
+        h.join().unwrap();
+    }
+
+    assert_eq!(cat.get_one::<TransactionCache>().unwrap().len(), 3);
+}
+
+#[test]
+fn test_transaction_concurrent_init() {
+    static INSTANCE_COUNT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+    struct A;
+
+    #[component]
+    #[scope(Transaction)]
+    impl A {
+        fn new() -> Self {
+            INSTANCE_COUNT.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            Self
+        }
+    }
+
+    let base = CatalogBuilder::new().add::<A>().build();
+    let cat = base
+        .builder_chained()
+        .add_value(TransactionCache::new())
+        .build();
+
+    let barrier = std::sync::Arc::new(std::sync::Barrier::new(8));
+    let handles: Vec<_> = (0..8)
+        .map(|_| {
+            let cat = cat.clone();
+            let barrier = barrier.clone();
+            std::thread::spawn(move || {
+                barrier.wait();
+                cat.get_one::<A>().unwrap()
+            })
+        })
+        .collect();
+
+    let instances: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+
+    assert_eq!(INSTANCE_COUNT.load(std::sync::atomic::Ordering::SeqCst), 1);
+    for inst in &instances[1..] {
+        assert!(std::sync::Arc::ptr_eq(&instances[0], inst));
+    }
+}
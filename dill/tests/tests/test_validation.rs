@@ -227,3 +227,301 @@ fn test_validate_scope_inversion_tx_in_singleton() {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_validate_keyed() {
+    #[derive(Clone, PartialEq, Eq, Hash)]
+    struct TenantId(u32);
+
+    #[dill::component]
+    #[dill::scope(dill::scopes::Keyed<TenantId>)]
+    struct A {
+        #[allow(dead_code)]
+        tenant: TenantId,
+        #[allow(dead_code)]
+        b: Arc<B>,
+    }
+
+    #[dill::component]
+    #[dill::scope(dill::scopes::Singleton)]
+    struct B;
+
+    #[dill::component]
+    #[dill::scope(dill::scopes::Singleton)]
+    struct C {
+        #[allow(dead_code)]
+        a: Lazy<Arc<A>>,
+    }
+
+    let mut b = CatalogBuilder::new();
+    b.add::<A>();
+    b.add::<B>();
+    b.add::<C>();
+
+    b.validate().unwrap();
+
+    // Key is recognized by its type and resolved via `KeyOf`
+    assert_eq!(
+        A::builder().dependencies_get_all()[0],
+        DependencyInfo::of::<TenantId, KeyOf<TenantId>>()
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_validate_ambiguous_and_shadowing() {
+    trait Clock: Send + Sync {}
+
+    #[component]
+    #[interface(dyn Clock)]
+    struct SystemClock;
+    impl Clock for SystemClock {}
+
+    #[component]
+    #[interface(dyn Clock)]
+    struct FakeClock;
+    impl Clock for FakeClock {}
+
+    #[allow(dead_code)]
+    #[component]
+    struct A {
+        clock: Arc<dyn Clock>,
+    }
+
+    #[allow(dead_code)]
+    #[component]
+    struct B {
+        clocks: Vec<Arc<dyn Clock>>,
+    }
+
+    let base = CatalogBuilder::new().add::<SystemClock>().build();
+
+    let mut b = base.builder_chained();
+    b.add::<FakeClock>().add::<A>().add::<B>();
+    assert_matches!(
+        b.validate(),
+        Err(ValidationError { errors }) if matches!(
+            &errors[..],
+            [InjectionError::Ambiguous(e)]
+            if e.dep_type.name == "dyn unit::tests::test_validation::test_validate_ambiguous_and_shadowing::Clock"
+        )
+    );
+
+    b.shadowing();
+    assert_matches!(b.validate(), Ok(()));
+
+    let mut b = base.builder_chained();
+    b.shadowing_mode(Shadowing::OneOf)
+        .add::<FakeClock>()
+        .add::<A>()
+        .add::<B>();
+    assert_matches!(b.validate(), Ok(()));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_validate_adapter() {
+    trait StorageV1: Send + Sync {}
+
+    trait StorageV2: Send + Sync {}
+
+    #[component]
+    #[interface(dyn StorageV2)]
+    struct StorageImpl;
+    impl StorageV2 for StorageImpl {}
+
+    struct Adapter(#[allow(dead_code)] Arc<dyn StorageV2>);
+    impl From<Arc<dyn StorageV2>> for Adapter {
+        fn from(inner: Arc<dyn StorageV2>) -> Self {
+            Self(inner)
+        }
+    }
+    impl StorageV1 for Adapter {}
+
+    #[allow(dead_code)]
+    #[component]
+    struct Consumer {
+        storage: Arc<dyn StorageV1>,
+    }
+
+    let mut b = CatalogBuilder::new();
+    b.add::<Consumer>()
+        .adapt::<dyn StorageV1, dyn StorageV2, Adapter>();
+    assert_matches!(
+        b.validate(),
+        Err(ValidationError { errors }) if matches!(
+            &errors[..],
+            [InjectionError::Unregistered(u)]
+            if u.dep_type.name == "dyn unit::tests::test_validation::test_validate_adapter::StorageV2"
+        )
+    );
+
+    b.add::<StorageImpl>();
+    assert_matches!(b.validate(), Ok(()));
+
+    // Adapted interface can be injected into singletons
+    #[allow(dead_code)]
+    #[component]
+    #[scope(Singleton)]
+    struct SingletonConsumer {
+        storage: Arc<dyn StorageV1>,
+    }
+
+    b.add::<SingletonConsumer>();
+    assert_matches!(b.validate(), Ok(()));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_validate_factory() {
+    trait Storage: Send + Sync {}
+
+    #[component]
+    #[interface(dyn Storage)]
+    struct StorageImpl;
+    impl Storage for StorageImpl {}
+
+    struct Service(#[allow(dead_code)] Arc<dyn Storage>);
+
+    let mut b = CatalogBuilder::new();
+    b.add_factory(|storage: Arc<dyn Storage>| Service(storage));
+    assert_matches!(
+        b.validate(),
+        Err(ValidationError { errors }) if matches!(
+            &errors[..],
+            [InjectionError::Unregistered(u)]
+            if u.dep_type.name == "dyn unit::tests::test_validation::test_validate_factory::Storage"
+        )
+    );
+
+    b.add::<StorageImpl>();
+    assert_matches!(b.validate(), Ok(()));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_validate_call() {
+    trait Storage: Send + Sync {}
+
+    #[component]
+    #[interface(dyn Storage)]
+    struct StorageImpl;
+    impl Storage for StorageImpl {}
+
+    let job =
+        |_storage: Arc<dyn Storage>, _all: Vec<Arc<dyn Storage>>, _name: Option<Arc<String>>| {};
+
+    let cat = CatalogBuilder::new().build();
+    assert_matches!(
+        cat.builder_chained().validate_call(&job),
+        Err(ValidationError { errors }) if matches!(
+            &errors[..],
+            [InjectionError::Unregistered(u)]
+            if u.dep_type.name == "dyn unit::tests::test_validation::test_validate_call::Storage"
+                && u.injection_stack.frames.len() == 1
+        )
+    );
+
+    let cat = CatalogBuilder::new().add::<StorageImpl>().build();
+    assert_matches!(cat.builder_chained().validate_call(&job), Ok(()));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_validate_owned() {
+    #[component]
+    struct Parser;
+
+    #[component]
+    #[scope(Singleton)]
+    struct Cache;
+
+    #[allow(dead_code)]
+    #[component]
+    #[scope(Singleton)]
+    struct A {
+        parser: Box<Parser>,
+    }
+
+    #[allow(dead_code)]
+    #[component]
+    struct B {
+        cache: Box<Cache>,
+    }
+
+    // Owned transient instance is fine in a singleton
+    let mut b = CatalogBuilder::new();
+    b.add::<Parser>().add::<A>();
+    assert_matches!(b.validate(), Ok(()));
+
+    // Cached instance cannot be owned
+    b.add::<Cache>().add::<B>();
+    assert_matches!(
+        b.validate(),
+        Err(ValidationError { errors }) if matches!(
+            &errors[..],
+            [InjectionError::Ownership(e)]
+            if e.dep_type.name == "unit::tests::test_validation::test_validate_owned::Cache"
+        )
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_validate_weak() {
+    #[component]
+    struct Transient;
+
+    #[allow(dead_code)]
+    #[component]
+    #[scope(Singleton)]
+    struct A {
+        t: std::sync::Weak<Transient>,
+    }
+
+    #[allow(dead_code)]
+    #[component]
+    #[scope(Singleton)]
+    struct B {
+        a: std::sync::Weak<A>,
+        missing: std::sync::Weak<String>,
+    }
+
+    // Weak references to instances that are not cached are dropped right away
+    let mut b = CatalogBuilder::new();
+    b.add::<Transient>().add::<A>().add::<B>();
+    let errors = b.validate().unwrap_err().errors;
+    assert_eq!(errors.len(), 2);
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        InjectionError::Unregistered(u) if u.dep_type == TypeInfo::of::<String>()
+    )));
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        InjectionError::ScopeInversion(e)
+            if e.inst_type == TypeInfo::of::<A>() && e.dep_type == TypeInfo::of::<Transient>()
+    )));
+
+    // ... unless they point back to the instance under construction
+    #[allow(dead_code)]
+    #[component]
+    struct Parent {
+        child: Arc<Child>,
+    }
+
+    #[allow(dead_code)]
+    #[component]
+    struct Child {
+        parent: std::sync::Weak<Parent>,
+    }
+
+    let mut b = CatalogBuilder::new();
+    b.add::<Parent>().add::<Child>();
+    b.validate().unwrap();
+}
@@ -0,0 +1,7 @@
+//! Checks the compile errors reported by the macros
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
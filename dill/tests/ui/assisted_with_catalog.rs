@@ -0,0 +1,10 @@
+use dill::*;
+
+#[component]
+struct Service {
+    #[component(assisted)]
+    value: i32,
+    catalog: Catalog,
+}
+
+fn main() {}
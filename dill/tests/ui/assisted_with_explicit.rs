@@ -0,0 +1,11 @@
+use dill::*;
+
+#[component]
+struct Service {
+    #[component(assisted)]
+    value: i32,
+    #[component(explicit)]
+    name: String,
+}
+
+fn main() {}
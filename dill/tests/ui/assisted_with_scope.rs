@@ -0,0 +1,10 @@
+use dill::*;
+
+#[component]
+#[scope(Singleton)]
+struct Service {
+    #[component(assisted)]
+    value: i32,
+}
+
+fn main() {}
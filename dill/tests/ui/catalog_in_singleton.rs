@@ -0,0 +1,9 @@
+use dill::*;
+
+#[component]
+#[scope(Singleton)]
+struct Service {
+    catalog: Catalog,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use dill::*;
+
+#[component]
+enum Color {
+    Red,
+    Green,
+}
+
+fn main() {}
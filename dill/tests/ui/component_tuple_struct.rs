@@ -0,0 +1,6 @@
+use dill::*;
+
+#[component]
+struct Service(i32);
+
+fn main() {}
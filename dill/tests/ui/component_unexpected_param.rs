@@ -0,0 +1,8 @@
+use dill::*;
+
+#[component(pub, no_builder)]
+struct Service {
+    value: i32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use dill::*;
+
+trait A: Send + Sync {}
+
+#[component]
+struct Service {
+    #[default(Vec::new())]
+    items: Vec<std::sync::Arc<dyn A>>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dill::*;
+
+#[component]
+struct Service {
+    #[component(explicit)]
+    #[inject(value)]
+    value: i32,
+}
+
+fn main() {}
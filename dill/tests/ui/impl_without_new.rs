@@ -0,0 +1,14 @@
+use dill::*;
+
+struct Service {
+    value: i32,
+}
+
+#[component]
+impl Service {
+    fn create(value: i32) -> Self {
+        Self { value }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dill::*;
+
+trait Greeter {}
+
+struct Service<T>(T);
+
+#[implements]
+impl<T> Greeter for Service<T> {}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use dill::*;
+
+#[component]
+struct Service;
+
+#[implements]
+impl Service {
+    fn value(&self) -> i32 {
+        42
+    }
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use dill::*;
+
+struct Service;
+
+#[component]
+impl Service {
+    fn new() -> Self {
+        Self
+    }
+
+    #[inject]
+    fn set_value(&self, value: i32) {
+        let _ = value;
+    }
+}
+
+fn main() {}
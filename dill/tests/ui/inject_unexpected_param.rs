@@ -0,0 +1,9 @@
+use dill::*;
+
+#[component]
+struct Service {
+    #[inject(values)]
+    value: i32,
+}
+
+fn main() {}
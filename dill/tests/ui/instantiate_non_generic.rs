@@ -0,0 +1,9 @@
+use dill::*;
+
+#[component]
+#[instantiate(i32)]
+struct Service {
+    value: i32,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use dill::*;
+
+trait A {}
+trait B {}
+
+#[component]
+#[interface(dyn A, extends(dyn B))]
+struct Service {
+    value: i32,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use dill::*;
+
+#[component]
+struct Service {
+    catalog: Option<Catalog>,
+}
+
+#[component]
+struct LazyService {
+    catalog: Lazy<Catalog>,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use dill::*;
+
+#[provider]
+async fn value() -> i32 {
+    42
+}
+
+fn main() {}
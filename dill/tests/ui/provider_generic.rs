@@ -0,0 +1,8 @@
+use dill::*;
+
+#[provider]
+fn value<T: Default>() -> T {
+    T::default()
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use dill::*;
+
+#[provider]
+fn value(_name: String) {}
+
+fn main() {}
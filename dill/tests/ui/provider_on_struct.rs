@@ -0,0 +1,8 @@
+use dill::*;
+
+#[provider]
+struct Service {
+    value: i32,
+}
+
+fn main() {}
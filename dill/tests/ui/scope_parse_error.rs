@@ -0,0 +1,9 @@
+use dill::*;
+
+#[component]
+#[scope("Singleton")]
+struct Service {
+    value: i32,
+}
+
+fn main() {}
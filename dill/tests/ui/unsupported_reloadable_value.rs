@@ -0,0 +1,8 @@
+use dill::*;
+
+#[component]
+struct Service {
+    value: Reloadable<i32>,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use dill::*;
+
+#[component]
+struct Service {
+    value: Result<std::sync::Arc<i32>, std::io::Error>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use dill::*;
+
+trait A: Send + Sync {}
+
+#[component]
+struct Service {
+    items: Vec<Option<std::sync::Arc<dyn A>>>,
+}
+
+fn main() {}